@@ -0,0 +1,44 @@
+#
+# Copyright (C) 2022 Vaticle
+#
+# Licensed to the Apache Software Foundation (ASF) under one
+# or more contributor license agreements.  See the NOTICE file
+# distributed with this work for additional information
+# regarding copyright ownership.  The ASF licenses this file
+# to you under the Apache License, Version 2.0 (the
+# "License"); you may not use this file except in compliance
+# with the License.  You may obtain a copy of the License at
+#
+#   http://www.apache.org/licenses/LICENSE-2.0
+#
+# Unless required by applicable law or agreed to in writing,
+# software distributed under the License is distributed on an
+# "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+# KIND, either express or implied.  See the License for the
+# specific language governing permissions and limitations
+# under the License.
+#
+
+load("@rules_rust//crate_universe:defs.bzl", "crate", "crates_repository")
+
+# Crates that are not provided by @vaticle_dependencies//library/crates. After editing the packages below,
+# regenerate the lockfiles with: CARGO_BAZEL_REPIN=true bazel sync --only=typedb_client_crates
+def typedb_client_crates():
+    crates_repository(
+        name = "typedb_client_crates",
+        cargo_lockfile = "//dependencies/crates:Cargo.lock",
+        lockfile = "//dependencies/crates:Cargo.Bazel.lock",
+        packages = {
+            "metrics": crate.spec(version = "0.21"),
+            "metrics-util": crate.spec(version = "0.15"),
+            "opentelemetry": crate.spec(version = "0.18"),
+            "proc-macro2": crate.spec(version = "1.0"),
+            "quote": crate.spec(version = "1.0"),
+            "serde": crate.spec(version = "1.0", features = ["derive"]),
+            "serde_json": crate.spec(version = "1.0"),
+            "syn": crate.spec(version = "2.0"),
+            "tracing": crate.spec(version = "0.1"),
+            "tracing-opentelemetry": crate.spec(version = "0.18"),
+            "tracing-subscriber": crate.spec(version = "0.3"),
+        },
+    )
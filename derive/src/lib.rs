@@ -0,0 +1,101 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Field, Fields, LitStr, Result};
+
+/// Derives `typedb_client::answer::FromConceptMap` for a struct with named fields.
+///
+/// Each field is read from the variable of the same name, converted with `FromConcept`.
+/// `#[typedb(var = "date-of-birth")]` reads the field from a differently named variable, and
+/// `#[typedb(iid)]` reads the IID of the thing bound to the variable into a `Vec<u8>` field.
+#[proc_macro_derive(FromConceptMap, attributes(typedb))]
+pub fn derive_from_concept_map(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "FromConceptMap can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => return Err(Error::new_spanned(&input, "FromConceptMap can only be derived for structs")),
+    };
+
+    let field_inits = fields.iter().map(field_init).collect::<Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::typedb_client::answer::FromConceptMap for #name #type_generics #where_clause {
+            fn from_concept_map(
+                concept_map: ::typedb_client::answer::ConceptMap,
+            ) -> ::typedb_client::Result<Self> {
+                ::std::result::Result::Ok(Self { #( #field_inits ),* })
+            }
+        }
+    })
+}
+
+fn field_init(field: &Field) -> Result<TokenStream2> {
+    let ident = field.ident.as_ref().unwrap();
+    let mut variable = ident.to_string().trim_start_matches("r#").to_owned();
+    let mut is_iid = false;
+
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("typedb")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("var") {
+                variable = meta.value()?.parse::<LitStr>()?.value();
+                Ok(())
+            } else if meta.path.is_ident("iid") {
+                is_iid = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported typedb attribute, expected `var = \"...\"` or `iid`"))
+            }
+        })?;
+    }
+
+    let ty = &field.ty;
+    if is_iid {
+        Ok(quote! {
+            #ident: <::typedb_client::concept::Thing as ::typedb_client::answer::FromConcept>::from_variable(
+                &concept_map,
+                #variable,
+            )?
+            .iid()
+            .to_vec()
+        })
+    } else {
+        Ok(quote! {
+            #ident: <#ty as ::typedb_client::answer::FromConcept>::from_variable(&concept_map, #variable)?
+        })
+    }
+}
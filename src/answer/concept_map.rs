@@ -27,8 +27,10 @@ use std::{
 use crate::concept::Concept;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConceptMap {
     pub map: HashMap<String, Concept>,
+    pub explainables: Explainables,
 }
 
 impl ConceptMap {
@@ -36,6 +38,10 @@ impl ConceptMap {
         self.map.get(var_name)
     }
 
+    pub fn explainables(&self) -> &Explainables {
+        &self.explainables
+    }
+
     pub fn concepts(&self) -> impl Iterator<Item = &Concept> {
         self.map.values()
     }
@@ -51,7 +57,7 @@ impl Clone for ConceptMap {
         for (k, v) in &self.map {
             map.insert(k.clone(), v.clone());
         }
-        Self { map }
+        Self { map, explainables: self.explainables.clone() }
     }
 }
 
@@ -77,3 +83,51 @@ impl IntoIterator for ConceptMap {
         self.map.into_iter()
     }
 }
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Explainables {
+    pub relations: HashMap<String, Explainable>,
+    pub attributes: HashMap<String, Explainable>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::ownerships"))]
+    pub ownerships: HashMap<(String, String), Explainable>,
+}
+
+impl Explainables {
+    pub fn new(
+        relations: HashMap<String, Explainable>,
+        attributes: HashMap<String, Explainable>,
+        ownerships: HashMap<(String, String), Explainable>,
+    ) -> Self {
+        Self { relations, attributes, ownerships }
+    }
+
+    pub fn relation(&self, variable: &str) -> Option<&Explainable> {
+        self.relations.get(variable)
+    }
+
+    pub fn attribute(&self, variable: &str) -> Option<&Explainable> {
+        self.attributes.get(variable)
+    }
+
+    pub fn ownership(&self, owner: &str, attribute: &str) -> Option<&Explainable> {
+        self.ownerships.get(&(owner.to_owned(), attribute.to_owned()))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relations.is_empty() && self.attributes.is_empty() && self.ownerships.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Explainable {
+    pub conjunction: String,
+    pub id: i64,
+}
+
+impl Explainable {
+    pub fn new(conjunction: String, id: i64) -> Self {
+        Self { conjunction, id }
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use chrono::NaiveDateTime;
+
+use super::ConceptMap;
+use crate::{
+    common::{error::ConceptError, Result},
+    concept::{Attribute, Concept, Thing, Value},
+};
+
+pub trait FromConceptMap: Sized {
+    fn from_concept_map(concept_map: ConceptMap) -> Result<Self>;
+}
+
+pub trait FromConcept: Sized {
+    fn from_concept(concept: &Concept) -> Result<Self>;
+
+    fn from_variable(concept_map: &ConceptMap, variable: &str) -> Result<Self> {
+        match concept_map.get(variable) {
+            Some(concept) => Self::from_concept(concept),
+            None => Err(ConceptError::MissingVariable(variable.to_owned()).into()),
+        }
+    }
+}
+
+impl<T: FromConcept> FromConcept for Option<T> {
+    fn from_concept(concept: &Concept) -> Result<Self> {
+        T::from_concept(concept).map(Some)
+    }
+
+    fn from_variable(concept_map: &ConceptMap, variable: &str) -> Result<Self> {
+        concept_map.get(variable).map(T::from_concept).transpose()
+    }
+}
+
+impl FromConcept for Concept {
+    fn from_concept(concept: &Concept) -> Result<Self> {
+        Ok(concept.clone())
+    }
+}
+
+impl FromConcept for Thing {
+    fn from_concept(concept: &Concept) -> Result<Self> {
+        match concept {
+            Concept::Thing(thing) => Ok(thing.clone()),
+            Concept::Type(_) => Err(ConceptError::UnexpectedConceptKind("thing", "type").into()),
+        }
+    }
+}
+
+impl FromConcept for Attribute {
+    fn from_concept(concept: &Concept) -> Result<Self> {
+        match Thing::from_concept(concept)? {
+            Thing::Attribute(attribute) => Ok(attribute),
+            Thing::Entity(_) => Err(ConceptError::UnexpectedConceptKind("attribute", "entity").into()),
+            Thing::Relation(_) => Err(ConceptError::UnexpectedConceptKind("attribute", "relation").into()),
+        }
+    }
+}
+
+macro_rules! from_concept_via_try_from {
+    { $( $type_:ty ),+ $(,)? } => { $(
+        impl FromConcept for $type_ {
+            fn from_concept(concept: &Concept) -> Result<Self> {
+                Self::try_from(concept)
+            }
+        }
+    )+ };
+}
+
+from_concept_via_try_from! { Value, bool, i64, f64, String, NaiveDateTime }
@@ -20,6 +20,18 @@
  */
 
 mod concept_map;
+mod concept_map_group;
+mod from_concept_map;
 mod numeric;
+mod numeric_group;
 
-pub use self::{concept_map::ConceptMap, numeric::Numeric};
+#[cfg(feature = "derive")]
+pub use typedb_client_derive::FromConceptMap;
+
+pub use self::{
+    concept_map::{ConceptMap, Explainable, Explainables},
+    concept_map_group::ConceptMapGroup,
+    from_concept_map::{FromConcept, FromConceptMap},
+    numeric::Numeric,
+    numeric_group::NumericGroup,
+};
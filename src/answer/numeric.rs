@@ -20,6 +20,7 @@
  */
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Numeric {
     Long(i64),
     Double(f64),
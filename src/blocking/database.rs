@@ -0,0 +1,59 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::executor::block_on;
+
+use crate::common::Result;
+
+#[derive(Debug)]
+pub struct Database {
+    database: crate::Database,
+}
+
+impl Database {
+    pub(super) fn new(database: crate::Database) -> Self {
+        Self { database }
+    }
+
+    pub(super) fn into_inner(self) -> crate::Database {
+        self.database
+    }
+
+    pub fn name(&self) -> &str {
+        self.database.name()
+    }
+
+    pub fn delete(self) -> Result {
+        block_on(self.database.delete())
+    }
+
+    pub fn schema(&self) -> Result<String> {
+        block_on(self.database.schema())
+    }
+
+    pub fn type_schema(&self) -> Result<String> {
+        block_on(self.database.type_schema())
+    }
+
+    pub fn rule_schema(&self) -> Result<String> {
+        block_on(self.database.rule_schema())
+    }
+}
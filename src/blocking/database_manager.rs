@@ -0,0 +1,52 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::executor::block_on;
+
+use super::Database;
+use crate::{common::Result, Connection};
+
+#[derive(Clone, Debug)]
+pub struct DatabaseManager {
+    database_manager: crate::DatabaseManager,
+}
+
+impl DatabaseManager {
+    pub fn new(connection: Connection) -> Self {
+        Self { database_manager: crate::DatabaseManager::new(connection) }
+    }
+
+    pub fn get(&self, name: impl Into<String>) -> Result<Database> {
+        block_on(self.database_manager.get(name)).map(Database::new)
+    }
+
+    pub fn contains(&self, name: impl Into<String>) -> Result<bool> {
+        block_on(self.database_manager.contains(name))
+    }
+
+    pub fn create(&self, name: impl Into<String>) -> Result {
+        block_on(self.database_manager.create(name))
+    }
+
+    pub fn all(&self) -> Result<Vec<Database>> {
+        block_on(self.database_manager.all()).map(|databases| databases.into_iter().map(Database::new).collect())
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+//! Blocking counterparts of `DatabaseManager`, `Session`, `Transaction` and `QueryManager`, which return iterators
+//! instead of streams. They must not be used from within an async context.
+//!
+//! The Concept and Logic APIs (`Transaction::concepts` and `Transaction::logic`) and `Session::run_in_transaction`
+//! are async-only, and have no blocking counterparts.
+
+mod database;
+mod database_manager;
+mod query;
+mod session;
+mod transaction;
+
+use futures::{executor, Stream};
+
+pub use self::{
+    database::Database, database_manager::DatabaseManager, query::QueryManager, session::Session,
+    transaction::Transaction,
+};
+use crate::Result;
+
+fn into_iter<T>(stream: impl Stream<Item = Result<T>>) -> impl Iterator<Item = Result<T>> {
+    executor::block_on_stream(Box::pin(stream))
+}
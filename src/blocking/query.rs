@@ -0,0 +1,170 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::executor::block_on;
+
+use super::into_iter;
+use crate::{
+    answer::{ConceptMap, ConceptMapGroup, Explainable, FromConceptMap, Numeric, NumericGroup},
+    common::Result,
+    logic::Explanation,
+    Options, QueryHandle,
+};
+
+#[derive(Debug)]
+pub struct QueryManager<'a> {
+    query_manager: &'a crate::database::QueryManager,
+}
+
+impl<'a> QueryManager<'a> {
+    pub(super) fn new(query_manager: &'a crate::database::QueryManager) -> Self {
+        Self { query_manager }
+    }
+
+    pub fn define(&self, query: &str) -> Result {
+        self.define_with_options(query, Options::new())
+    }
+
+    pub fn define_with_options(&self, query: &str, options: Options) -> Result {
+        block_on(self.query_manager.define_with_options(query, options))
+    }
+
+    pub fn undefine(&self, query: &str) -> Result {
+        self.undefine_with_options(query, Options::new())
+    }
+
+    pub fn undefine_with_options(&self, query: &str, options: Options) -> Result {
+        block_on(self.query_manager.undefine_with_options(query, options))
+    }
+
+    pub fn delete(&self, query: &str) -> Result {
+        self.delete_with_options(query, Options::new())
+    }
+
+    pub fn delete_with_options(&self, query: &str, options: Options) -> Result {
+        block_on(self.query_manager.delete_with_options(query, options))
+    }
+
+    pub fn match_(&self, query: &str) -> Result<impl Iterator<Item = Result<ConceptMap>>> {
+        self.match_with_options(query, Options::new())
+    }
+
+    pub fn match_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<ConceptMap>>> {
+        self.query_manager.match_with_options(query, options).map(into_iter)
+    }
+
+    pub fn match_cancellable(&self, query: &str) -> Result<(impl Iterator<Item = Result<ConceptMap>>, QueryHandle)> {
+        self.match_cancellable_with_options(query, Options::new())
+    }
+
+    pub fn match_cancellable_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<(impl Iterator<Item = Result<ConceptMap>>, QueryHandle)> {
+        let (stream, handle) = self.query_manager.match_cancellable_with_options(query, options)?;
+        Ok((into_iter(stream), handle))
+    }
+
+    pub fn match_as<T: FromConceptMap>(&self, query: &str) -> Result<impl Iterator<Item = Result<T>>> {
+        self.match_as_with_options(query, Options::new())
+    }
+
+    pub fn match_as_with_options<T: FromConceptMap>(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<T>>> {
+        self.query_manager.match_as_with_options(query, options).map(into_iter)
+    }
+
+    pub fn insert(&self, query: &str) -> Result<impl Iterator<Item = Result<ConceptMap>>> {
+        self.insert_with_options(query, Options::new())
+    }
+
+    pub fn insert_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<ConceptMap>>> {
+        self.query_manager.insert_with_options(query, options).map(into_iter)
+    }
+
+    pub fn update(&self, query: &str) -> Result<impl Iterator<Item = Result<ConceptMap>>> {
+        self.update_with_options(query, Options::new())
+    }
+
+    pub fn update_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<ConceptMap>>> {
+        self.query_manager.update_with_options(query, options).map(into_iter)
+    }
+
+    pub fn match_aggregate(&self, query: &str) -> Result<Numeric> {
+        self.match_aggregate_with_options(query, Options::new())
+    }
+
+    pub fn match_aggregate_with_options(&self, query: &str, options: Options) -> Result<Numeric> {
+        block_on(self.query_manager.match_aggregate_with_options(query, options))
+    }
+
+    pub fn match_group(&self, query: &str) -> Result<impl Iterator<Item = Result<ConceptMapGroup>>> {
+        self.match_group_with_options(query, Options::new())
+    }
+
+    pub fn match_group_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<ConceptMapGroup>>> {
+        self.query_manager.match_group_with_options(query, options).map(into_iter)
+    }
+
+    pub fn match_group_aggregate(&self, query: &str) -> Result<impl Iterator<Item = Result<NumericGroup>>> {
+        self.match_group_aggregate_with_options(query, Options::new())
+    }
+
+    pub fn match_group_aggregate_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<NumericGroup>>> {
+        self.query_manager.match_group_aggregate_with_options(query, options).map(into_iter)
+    }
+
+    pub fn explain(&self, explainable: &Explainable) -> Result<impl Iterator<Item = Result<Explanation>>> {
+        self.explain_with_options(explainable, Options::new())
+    }
+
+    pub fn explain_with_options(
+        &self,
+        explainable: &Explainable,
+        options: Options,
+    ) -> Result<impl Iterator<Item = Result<Explanation>>> {
+        self.query_manager.explain_with_options(explainable, options).map(into_iter)
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::executor::block_on;
+
+use super::{Database, Transaction};
+use crate::{
+    common::{Result, SessionType, TransactionType},
+    Error, Options,
+};
+
+#[derive(Debug)]
+pub struct Session {
+    session: crate::Session,
+}
+
+impl Session {
+    pub fn new(database: Database, session_type: SessionType) -> Result<Self> {
+        Self::new_with_options(database, session_type, Options::new())
+    }
+
+    pub fn new_with_options(database: Database, session_type: SessionType, options: Options) -> Result<Self> {
+        Ok(Self { session: block_on(crate::Session::new_with_options(database.into_inner(), session_type, options))? })
+    }
+
+    pub fn database_name(&self) -> &str {
+        self.session.database_name()
+    }
+
+    pub fn type_(&self) -> SessionType {
+        self.session.type_()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.session.is_open()
+    }
+
+    pub fn on_close(&self, callback: impl FnOnce(Option<Error>) + Send + 'static) {
+        self.session.on_close(callback)
+    }
+
+    pub fn force_close(&self) -> Result {
+        self.session.force_close()
+    }
+
+    pub fn transaction(&self, transaction_type: TransactionType) -> Result<Transaction> {
+        self.transaction_with_options(transaction_type, Options::new())
+    }
+
+    pub fn transaction_with_options(&self, transaction_type: TransactionType, options: Options) -> Result<Transaction> {
+        block_on(self.session.transaction_with_options(transaction_type, options)).map(Transaction::new)
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::fmt;
+
+use futures::executor::block_on;
+
+use super::QueryManager;
+use crate::{
+    common::{Result, TransactionType},
+    Error,
+};
+
+pub struct Transaction<'a> {
+    transaction: crate::Transaction<'a>,
+}
+
+impl<'a> Transaction<'a> {
+    pub(super) fn new(transaction: crate::Transaction<'a>) -> Self {
+        Self { transaction }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.transaction.is_open()
+    }
+
+    pub fn type_(&self) -> TransactionType {
+        self.transaction.type_()
+    }
+
+    pub fn query(&self) -> QueryManager<'_> {
+        QueryManager::new(self.transaction.query())
+    }
+
+    pub fn commit(self) -> Result {
+        block_on(self.transaction.commit())
+    }
+
+    pub fn rollback(&self) -> Result {
+        block_on(self.transaction.rollback())
+    }
+
+    pub fn close(self) -> Result {
+        block_on(self.transaction.close())
+    }
+
+    pub fn on_close(&self, callback: impl FnOnce(Option<Error>) + Send + 'static) {
+        self.transaction.on_close(callback)
+    }
+}
+
+impl fmt::Debug for Transaction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transaction").field("transaction", &self.transaction).finish()
+    }
+}
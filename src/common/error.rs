@@ -24,7 +24,7 @@ use std::{error::Error as StdError, fmt};
 use tonic::{Code, Status};
 use typeql_lang::error_messages;
 
-use crate::common::RequestID;
+use crate::{common::RequestID, concept::attribute::ValueType};
 
 error_messages! { ConnectionError
     code: "CXN", type: "Connection Error",
@@ -54,6 +54,8 @@ error_messages! { ConnectionError
         16: "Invalid token credential.",
     SessionCloseFailed() =
         17: "Failed to close session. It may still be open on the server: or it may already have been closed previously.",
+    UserManagementClusterOnly() =
+        18: "User management is only available when connected to TypeDB Cluster.",
 }
 
 error_messages! { InternalError
@@ -70,20 +72,97 @@ error_messages! { InternalError
         5: "Received unrecognized address from the server: {}.",
     EnumOutOfBounds(i32, &'static str) =
         6: "Value '{}' is out of bounds for enum '{}'.",
+    UnexpectedRulePattern(String) =
+        7: "Received a rule with an unexpected pattern from the server: '{}'.",
+    UnexpectedConceptEncoding(i32, &'static str) =
+        8: "Received a concept with encoding '{}' where a '{}' was expected.",
+    RuleParseFailed(String, String) =
+        9: "Failed to parse the rule pattern '{}' received from the server: {}.",
 }
 
+error_messages! { ConceptError
+    code: "CPT", type: "Concept Error",
+    UnexpectedConceptKind(&'static str, &'static str) =
+        1: "Expected a concept of kind '{}', but received a concept of kind '{}'.",
+    UnexpectedValueType(ValueType, ValueType) =
+        2: "Expected an attribute with value type '{}', but received an attribute with value type '{}'.",
+    MissingVariable(String) =
+        3: "The concept map does not contain the variable '{}'.",
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerError {
+    pub code: String,
+    pub message: String,
+}
+
+impl ServerError {
+    const CODE_LEN: usize = 5;
+    // Transaction isolation violations (modify-delete, delete-modify and exclusive-create), raised at commit when a
+    // concurrent transaction modified the same data.
+    const COMMIT_CONFLICT_CODES: [&'static str; 3] = ["TXN13", "TXN14", "TXN15"];
+
+    fn parse(message: &str) -> Option<Self> {
+        message.match_indices('[').find_map(|(start, _)| {
+            let code = message.get(start + 1..start + 1 + Self::CODE_LEN)?;
+            let is_code = code.bytes().take(3).all(|b| b.is_ascii_uppercase())
+                && code.bytes().skip(3).all(|b| b.is_ascii_digit())
+                && message[start + 1 + Self::CODE_LEN..].starts_with(']');
+            is_code.then(|| Self { code: code.to_owned(), message: message.to_owned() })
+        })
+    }
+
+    pub fn domain(&self) -> &str {
+        &self.code[..3]
+    }
+
+    pub fn is_schema_violation(&self) -> bool {
+        matches!(self.domain(), "THW" | "TYW" | "RUW")
+    }
+
+    pub fn is_commit_conflict(&self) -> bool {
+        Self::COMMIT_CONFLICT_CODES.contains(&self.code.as_str())
+    }
+
+    pub fn is_query_syntax_error(&self) -> bool {
+        self.domain() == "TQL"
+    }
+}
+
+impl fmt::Display for ServerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl StdError for ServerError {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Error {
     Connection(ConnectionError),
+    Concept(ConceptError),
     Internal(InternalError),
+    Server(ServerError),
     Other(String),
 }
 
+impl Error {
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            Error::Connection(ConnectionError::ClusterReplicaNotPrimary() | ConnectionError::UnableToConnect()) => true,
+            Error::Server(error) => error.is_commit_conflict(),
+            _ => false,
+        }
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Connection(error) => write!(f, "{error}"),
+            Error::Concept(error) => write!(f, "{error}"),
             Error::Internal(error) => write!(f, "{error}"),
+            Error::Server(error) => write!(f, "{error}"),
             Error::Other(message) => write!(f, "{message}"),
         }
     }
@@ -93,7 +172,9 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Error::Connection(error) => Some(error),
+            Error::Concept(error) => Some(error),
             Error::Internal(error) => Some(error),
+            Error::Server(error) => Some(error),
             Error::Other(_) => None,
         }
     }
@@ -105,6 +186,12 @@ impl From<ConnectionError> for Error {
     }
 }
 
+impl From<ConceptError> for Error {
+    fn from(error: ConceptError) -> Self {
+        Error::Concept(error)
+    }
+}
+
 impl From<InternalError> for Error {
     fn from(error: InternalError) -> Self {
         Error::Internal(error)
@@ -119,6 +206,8 @@ impl From<Status> for Error {
             Self::Connection(ConnectionError::ClusterReplicaNotPrimary())
         } else if is_token_credential_invalid(&status) {
             Self::Connection(ConnectionError::ClusterTokenCredentialInvalid())
+        } else if let Some(error) = ServerError::parse(status.message()) {
+            Self::Server(error)
         } else {
             Self::Other(status.message().to_string())
         }
@@ -187,3 +276,51 @@ impl From<std::io::Error> for Error {
         Error::Other(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ServerError;
+
+    fn code(message: &str) -> Option<String> {
+        ServerError::parse(message).map(|error| error.code)
+    }
+
+    #[test]
+    fn parse_without_code() {
+        assert_eq!(code("Connection refused."), None);
+        assert_eq!(code(""), None);
+    }
+
+    #[test]
+    fn parse_malformed_brackets() {
+        assert_eq!(code("[TXN13 Isolation violation."), None);
+        assert_eq!(code("TXN13] Isolation violation."), None);
+        assert_eq!(code("[TX13] Isolation violation."), None);
+        assert_eq!(code("[txn13] Isolation violation."), None);
+        assert_eq!(code("[TXN133] Isolation violation."), None);
+        assert_eq!(code("Isolation violation. [TXN1"), None);
+    }
+
+    #[test]
+    fn parse_code_in_middle_of_message() {
+        let error = ServerError::parse("Transaction failed: [TXN13] Isolation violation.").unwrap();
+        assert_eq!(error.code, "TXN13");
+        assert_eq!(error.message, "Transaction failed: [TXN13] Isolation violation.");
+        assert!(error.is_commit_conflict());
+    }
+
+    #[test]
+    fn parse_nested_brackets() {
+        assert_eq!(code("[[TXN13]] Isolation violation."), Some(String::from("TXN13")));
+        assert_eq!(code("[see [TQL03]] Syntax error."), Some(String::from("TQL03")));
+        assert_eq!(code("[ab] [c] [TYR03] Invalid type read."), Some(String::from("TYR03")));
+    }
+
+    #[test]
+    fn commit_conflict_is_matched_by_code() {
+        assert!(!ServerError::parse("[TXN05] Isolation violation mentioned in a closed transaction.")
+            .unwrap()
+            .is_commit_conflict());
+        assert!(ServerError::parse("[TXN14] Concurrent delete.").unwrap().is_commit_conflict());
+    }
+}
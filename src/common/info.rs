@@ -36,6 +36,12 @@ pub(crate) struct DatabaseInfo {
     pub(crate) replicas: Vec<ReplicaInfo>,
 }
 
+#[derive(Debug)]
+pub(crate) struct UserInfo {
+    pub(crate) username: String,
+    pub(crate) password_expiry_seconds: Option<i64>,
+}
+
 #[derive(Debug)]
 pub(crate) struct ReplicaInfo {
     pub(crate) address: Address,
@@ -43,3 +49,13 @@ pub(crate) struct ReplicaInfo {
     pub(crate) is_preferred: bool,
     pub(crate) term: i64,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ServerStatus {
+    pub address: String,
+    pub is_reachable: bool,
+    /// Round-trip time of the health probe that listed the server's databases, including the server's own
+    /// processing time. `None` if the server could not be reached.
+    pub latency: Option<Duration>,
+    pub primary_databases: Vec<String>,
+}
@@ -0,0 +1,93 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+#![cfg_attr(not(feature = "metrics"), allow(dead_code, unused_variables))]
+
+use std::time::Duration;
+
+#[cfg(feature = "metrics")]
+use metrics::{counter, decrement_gauge, histogram, increment_gauge};
+
+const REQUEST_DURATION: &str = "typedb_client_request_duration_seconds";
+const REQUESTS_IN_FLIGHT: &str = "typedb_client_requests_in_flight";
+const OPEN_SESSIONS: &str = "typedb_client_open_sessions";
+const OPEN_TRANSACTIONS: &str = "typedb_client_open_transactions";
+const TRANSACTION_BATCH_REQUESTS: &str = "typedb_client_transaction_batch_requests";
+const TRANSACTION_BATCH_BYTES: &str = "typedb_client_transaction_batch_bytes";
+const ANSWERS: &str = "typedb_client_answers_total";
+const FAILOVERS: &str = "typedb_client_failovers_total";
+const TOKEN_RENEWALS: &str = "typedb_client_token_renewals_total";
+
+pub(crate) fn request_started(request: &'static str) {
+    #[cfg(feature = "metrics")]
+    increment_gauge!(REQUESTS_IN_FLIGHT, 1.0, "request" => request);
+}
+
+pub(crate) fn request_finished(request: &'static str, duration: Duration) {
+    #[cfg(feature = "metrics")]
+    {
+        decrement_gauge!(REQUESTS_IN_FLIGHT, 1.0, "request" => request);
+        histogram!(REQUEST_DURATION, duration.as_secs_f64(), "request" => request);
+    }
+}
+
+pub(crate) fn session_opened() {
+    #[cfg(feature = "metrics")]
+    increment_gauge!(OPEN_SESSIONS, 1.0);
+}
+
+pub(crate) fn session_closed() {
+    #[cfg(feature = "metrics")]
+    decrement_gauge!(OPEN_SESSIONS, 1.0);
+}
+
+pub(crate) fn transaction_opened() {
+    #[cfg(feature = "metrics")]
+    increment_gauge!(OPEN_TRANSACTIONS, 1.0);
+}
+
+pub(crate) fn transaction_closed() {
+    #[cfg(feature = "metrics")]
+    decrement_gauge!(OPEN_TRANSACTIONS, 1.0);
+}
+
+pub(crate) fn transaction_batch_sent(requests: usize, bytes: usize) {
+    #[cfg(feature = "metrics")]
+    {
+        histogram!(TRANSACTION_BATCH_REQUESTS, requests as f64);
+        histogram!(TRANSACTION_BATCH_BYTES, bytes as f64);
+    }
+}
+
+pub(crate) fn answers_received(count: usize) {
+    #[cfg(feature = "metrics")]
+    counter!(ANSWERS, count as u64);
+}
+
+pub(crate) fn failover(reason: &'static str) {
+    #[cfg(feature = "metrics")]
+    counter!(FAILOVERS, 1, "reason" => reason);
+}
+
+pub(crate) fn token_renewed() {
+    #[cfg(feature = "metrics")]
+    counter!(TOKEN_RENEWALS, 1);
+}
@@ -24,9 +24,15 @@ mod credential;
 pub mod error;
 mod id;
 pub(crate) mod info;
+pub(crate) mod metrics;
 mod options;
+#[cfg(feature = "serde")]
+pub(crate) mod serialization;
+mod settings;
 
-pub use self::{credential::Credential, error::Error, options::Options};
+pub use self::{
+    credential::Credential, error::Error, info::ServerStatus, options::Options, settings::ConnectionSettings,
+};
 
 pub(crate) type StdResult<T, E> = std::result::Result<T, E>;
 pub type Result<T = ()> = StdResult<T, Error>;
@@ -0,0 +1,91 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{collections::HashMap, fmt::Write};
+
+use chrono::NaiveDateTime;
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+use crate::answer::Explainable;
+
+pub(crate) mod iid {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(iid: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut hex = String::with_capacity(2 + iid.len() * 2);
+        hex.push_str("0x");
+        for byte in iid {
+            write!(hex, "{byte:02x}").unwrap();
+        }
+        serializer.serialize_str(&hex)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        let digits = hex.strip_prefix("0x").unwrap_or(&hex);
+        if digits.len() % 2 != 0 {
+            return Err(D::Error::custom(format!("invalid IID '{hex}': odd number of hex digits")));
+        }
+        (0..digits.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&digits[i..i + 2], 16)
+                    .map_err(|err| D::Error::custom(format!("invalid IID '{hex}': {err}")))
+            })
+            .collect()
+    }
+}
+
+pub(crate) mod date_time {
+    use super::*;
+
+    const FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+    pub(crate) fn serialize<S: Serializer>(date_time: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&date_time.format(FORMAT))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDateTime, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        NaiveDateTime::parse_from_str(&string, FORMAT).map_err(D::Error::custom)
+    }
+}
+
+// Tuple keys cannot be represented as map keys in most formats (e.g. JSON), so ownerships are
+// serialized as a sequence of `(owner, attribute, explainable)` entries instead.
+pub(crate) mod ownerships {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        ownerships: &HashMap<(String, String), Explainable>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer
+            .collect_seq(ownerships.iter().map(|((owner, attribute), explainable)| (owner, attribute, explainable)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<(String, String), Explainable>, D::Error> {
+        let entries = Vec::<(String, String, Explainable)>::deserialize(deserializer)?;
+        Ok(entries.into_iter().map(|(owner, attribute, explainable)| ((owner, attribute), explainable)).collect())
+    }
+}
@@ -0,0 +1,117 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::time::Duration;
+
+#[cfg(feature = "testing")]
+use crate::testing::FaultPolicy;
+
+#[derive(Clone, Debug)]
+pub struct ConnectionSettings {
+    pub(crate) session_pulse_interval: Duration,
+    pub(crate) transaction_dispatch_interval: Duration,
+    pub(crate) transaction_max_message_len: usize,
+    pub(crate) primary_replica_task_max_retries: usize,
+    pub(crate) fetch_replicas_max_retries: usize,
+    pub(crate) primary_replica_selection_wait: Duration,
+    pub(crate) transaction_retry_limit: usize,
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) request_timeout: Option<Duration>,
+    pub(crate) keepalive_interval: Option<Duration>,
+    pub(crate) server_refresh_interval: Option<Duration>,
+    #[cfg(feature = "testing")]
+    pub(crate) fault_policy: Option<FaultPolicy>,
+}
+
+impl Default for ConnectionSettings {
+    fn default() -> Self {
+        Self {
+            session_pulse_interval: Duration::from_secs(5),
+            transaction_dispatch_interval: Duration::from_millis(3),
+            transaction_max_message_len: 1_000_000,
+            primary_replica_task_max_retries: 10,
+            fetch_replicas_max_retries: 10,
+            primary_replica_selection_wait: Duration::from_secs(2),
+            transaction_retry_limit: 3,
+            connect_timeout: None,
+            request_timeout: None,
+            keepalive_interval: None,
+            server_refresh_interval: None,
+            #[cfg(feature = "testing")]
+            fault_policy: None,
+        }
+    }
+}
+
+impl ConnectionSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn session_pulse_interval(self, interval: Duration) -> Self {
+        Self { session_pulse_interval: interval, ..self }
+    }
+
+    pub fn transaction_dispatch_interval(self, interval: Duration) -> Self {
+        Self { transaction_dispatch_interval: interval, ..self }
+    }
+
+    pub fn transaction_max_message_len(self, len: usize) -> Self {
+        Self { transaction_max_message_len: len, ..self }
+    }
+
+    pub fn primary_replica_task_max_retries(self, retries: usize) -> Self {
+        Self { primary_replica_task_max_retries: retries, ..self }
+    }
+
+    pub fn fetch_replicas_max_retries(self, retries: usize) -> Self {
+        Self { fetch_replicas_max_retries: retries, ..self }
+    }
+
+    pub fn primary_replica_selection_wait(self, wait: Duration) -> Self {
+        Self { primary_replica_selection_wait: wait, ..self }
+    }
+
+    pub fn transaction_retry_limit(self, limit: usize) -> Self {
+        Self { transaction_retry_limit: limit, ..self }
+    }
+
+    pub fn connect_timeout(self, timeout: Duration) -> Self {
+        Self { connect_timeout: Some(timeout), ..self }
+    }
+
+    pub fn request_timeout(self, timeout: Duration) -> Self {
+        Self { request_timeout: Some(timeout), ..self }
+    }
+
+    pub fn keepalive_interval(self, interval: Duration) -> Self {
+        Self { keepalive_interval: Some(interval), ..self }
+    }
+
+    pub fn server_refresh_interval(self, interval: Duration) -> Self {
+        Self { server_refresh_interval: Some(interval), ..self }
+    }
+
+    #[cfg(feature = "testing")]
+    pub fn fault_policy(self, policy: FaultPolicy) -> Self {
+        Self { fault_policy: Some(policy), ..self }
+    }
+}
@@ -22,6 +22,10 @@
 #![allow(dead_code)]
 #![allow(unused)]
 
+mod thing;
+mod thing_type;
+mod value;
+
 use std::{
     convert::TryFrom,
     fmt,
@@ -34,18 +38,21 @@ use futures::{FutureExt, Stream, StreamExt};
 use crate::common::{error::ConnectionError, Result};
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Concept {
     Type(Type),
     Thing(Thing),
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
     Thing(ThingType),
     Role(RoleType),
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThingType {
     Root(RootThingType),
     Entity(EntityType),
@@ -54,12 +61,14 @@ pub enum ThingType {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EntityOrThingType {
     EntityType(EntityType),
     RootThingType(RootThingType),
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RootThingType {
     pub label: String,
 }
@@ -79,6 +88,7 @@ impl Default for RootThingType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityType {
     pub label: String,
 }
@@ -90,6 +100,7 @@ impl EntityType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelationType {
     pub label: String,
 }
@@ -101,6 +112,7 @@ impl RelationType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AttributeType {
     Root(RootAttributeType),
     Boolean(BooleanAttributeType),
@@ -111,6 +123,7 @@ pub enum AttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RootAttributeType {
     pub label: String,
 }
@@ -130,6 +143,7 @@ impl Default for RootAttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BooleanAttributeType {
     pub label: String,
 }
@@ -141,6 +155,7 @@ impl BooleanAttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LongAttributeType {
     pub label: String,
 }
@@ -152,6 +167,7 @@ impl LongAttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoubleAttributeType {
     pub label: String,
 }
@@ -163,6 +179,7 @@ impl DoubleAttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringAttributeType {
     pub label: String,
 }
@@ -174,6 +191,7 @@ impl StringAttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTimeAttributeType {
     pub label: String,
 }
@@ -185,6 +203,7 @@ impl DateTimeAttributeType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoleType {
     pub label: ScopedLabel,
 }
@@ -196,6 +215,7 @@ impl RoleType {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 // #[enum_dispatch(ThingApi)]
 pub enum Thing {
     Entity(Entity),
@@ -218,7 +238,9 @@ pub enum Thing {
 // TODO: Storing the Type here is *extremely* inefficient; we could be effectively creating
 //       1 million copies of the same data when matching concepts of homogeneous types
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Entity {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
     pub type_: EntityType,
 }
@@ -235,7 +257,9 @@ pub struct Entity {
 // impl EntityApi for Entity {}
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Relation {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
     pub type_: RelationType,
 }
@@ -259,6 +283,7 @@ pub struct Relation {
 // impl RelationApi for Relation {}
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Attribute {
     Boolean(BooleanAttribute),
     Long(LongAttribute),
@@ -267,14 +292,28 @@ pub enum Attribute {
     DateTime(DateTimeAttribute),
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Value {
+    Boolean(bool),
+    Long(i64),
+    Double(f64),
+    String(String),
+    DateTime(#[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::date_time"))] NaiveDateTime),
+}
+
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BooleanAttribute {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
     pub value: bool,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LongAttribute {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
     pub value: i64,
 }
@@ -282,13 +321,17 @@ pub struct LongAttribute {
 impl LongAttribute {}
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DoubleAttribute {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
     pub value: f64,
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StringAttribute {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
     pub value: String,
 }
@@ -296,13 +339,19 @@ pub struct StringAttribute {
 impl StringAttribute {}
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTimeAttribute {
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::iid"))]
     pub iid: Vec<u8>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::common::serialization::date_time"))]
     pub value: NaiveDateTime,
 }
 
 pub mod attribute {
-    #[derive(Copy, Clone, Debug)]
+    use std::fmt;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum ValueType {
         Object = 0,
         Boolean = 1,
@@ -311,9 +360,23 @@ pub mod attribute {
         String = 4,
         DateTime = 5,
     }
+
+    impl fmt::Display for ValueType {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::Object => write!(f, "object"),
+                Self::Boolean => write!(f, "boolean"),
+                Self::Long => write!(f, "long"),
+                Self::Double => write!(f, "double"),
+                Self::String => write!(f, "string"),
+                Self::DateTime => write!(f, "datetime"),
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScopedLabel {
     pub scope: String,
     pub name: String,
@@ -330,3 +393,16 @@ impl fmt::Display for ScopedLabel {
         write!(f, "{}:{}", self.scope, self.name)
     }
 }
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaException {
+    pub code: String,
+    pub message: String,
+}
+
+impl fmt::Display for SchemaException {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.code, self.message)
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::Stream;
+
+use super::{Attribute, AttributeType, Entity, Relation, RoleType, Thing, ThingType, Value};
+use crate::{common::Result, Transaction};
+
+impl Thing {
+    pub fn iid(&self) -> &[u8] {
+        match self {
+            Self::Entity(entity) => &entity.iid,
+            Self::Relation(relation) => &relation.iid,
+            Self::Attribute(attribute) => attribute.iid(),
+        }
+    }
+
+    pub async fn delete(&self, transaction: &Transaction<'_>) -> Result {
+        transaction.transaction_stream().thing_delete(self.iid().to_vec()).await
+    }
+
+    pub fn get_has(
+        &self,
+        transaction: &Transaction<'_>,
+        attribute_types: Vec<AttributeType>,
+        keys_only: bool,
+    ) -> Result<impl Stream<Item = Result<Attribute>>> {
+        transaction.transaction_stream().thing_get_has(self.iid().to_vec(), attribute_types, keys_only)
+    }
+
+    pub async fn set_has(&self, transaction: &Transaction<'_>, attribute: Attribute) -> Result {
+        transaction.transaction_stream().thing_set_has(self.iid().to_vec(), attribute).await
+    }
+
+    pub async fn unset_has(&self, transaction: &Transaction<'_>, attribute: Attribute) -> Result {
+        transaction.transaction_stream().thing_unset_has(self.iid().to_vec(), attribute).await
+    }
+
+    pub fn get_relations(
+        &self,
+        transaction: &Transaction<'_>,
+        role_types: Vec<RoleType>,
+    ) -> Result<impl Stream<Item = Result<Relation>>> {
+        transaction.transaction_stream().thing_get_relations(self.iid().to_vec(), role_types)
+    }
+
+    pub fn get_playing(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<RoleType>>> {
+        transaction.transaction_stream().thing_get_playing(self.iid().to_vec())
+    }
+}
+
+impl Relation {
+    pub fn get_players_by_role_type(
+        &self,
+        transaction: &Transaction<'_>,
+    ) -> Result<impl Stream<Item = Result<(RoleType, Thing)>>> {
+        transaction.transaction_stream().relation_get_players_by_role_type(self.iid.clone())
+    }
+
+    pub async fn add_player(&self, transaction: &Transaction<'_>, role_type: RoleType, player: Thing) -> Result {
+        transaction.transaction_stream().relation_add_player(self.iid.clone(), role_type, player).await
+    }
+
+    pub async fn remove_player(&self, transaction: &Transaction<'_>, role_type: RoleType, player: Thing) -> Result {
+        transaction.transaction_stream().relation_remove_player(self.iid.clone(), role_type, player).await
+    }
+}
+
+impl Attribute {
+    pub fn iid(&self) -> &[u8] {
+        match self {
+            Self::Boolean(attribute) => &attribute.iid,
+            Self::Long(attribute) => &attribute.iid,
+            Self::Double(attribute) => &attribute.iid,
+            Self::String(attribute) => &attribute.iid,
+            Self::DateTime(attribute) => &attribute.iid,
+        }
+    }
+
+    pub fn value(&self) -> Value {
+        match self {
+            Self::Boolean(attribute) => Value::Boolean(attribute.value),
+            Self::Long(attribute) => Value::Long(attribute.value),
+            Self::Double(attribute) => Value::Double(attribute.value),
+            Self::String(attribute) => Value::String(attribute.value.clone()),
+            Self::DateTime(attribute) => Value::DateTime(attribute.value),
+        }
+    }
+
+    pub fn get_owners(
+        &self,
+        transaction: &Transaction<'_>,
+        thing_type: Option<ThingType>,
+    ) -> Result<impl Stream<Item = Result<Thing>>> {
+        transaction.transaction_stream().attribute_get_owners(self.iid().to_vec(), thing_type)
+    }
+}
+
+macro_rules! thing_api {
+    { $( $type_:ident => $variant:ident ),+ $(,)? } => { $(
+        impl From<$type_> for Thing {
+            fn from(thing: $type_) -> Self {
+                Self::$variant(thing)
+            }
+        }
+
+        impl $type_ {
+            pub async fn delete(&self, transaction: &Transaction<'_>) -> Result {
+                Thing::from(self.clone()).delete(transaction).await
+            }
+
+            pub fn get_has(
+                &self,
+                transaction: &Transaction<'_>,
+                attribute_types: Vec<AttributeType>,
+                keys_only: bool,
+            ) -> Result<impl Stream<Item = Result<Attribute>>> {
+                Thing::from(self.clone()).get_has(transaction, attribute_types, keys_only)
+            }
+
+            pub async fn set_has(&self, transaction: &Transaction<'_>, attribute: Attribute) -> Result {
+                Thing::from(self.clone()).set_has(transaction, attribute).await
+            }
+
+            pub async fn unset_has(&self, transaction: &Transaction<'_>, attribute: Attribute) -> Result {
+                Thing::from(self.clone()).unset_has(transaction, attribute).await
+            }
+
+            pub fn get_relations(
+                &self,
+                transaction: &Transaction<'_>,
+                role_types: Vec<RoleType>,
+            ) -> Result<impl Stream<Item = Result<Relation>>> {
+                Thing::from(self.clone()).get_relations(transaction, role_types)
+            }
+
+            pub fn get_playing(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<RoleType>>> {
+                Thing::from(self.clone()).get_playing(transaction)
+            }
+        }
+    )+ };
+}
+
+thing_api! {
+    Entity => Entity,
+    Relation => Relation,
+    Attribute => Attribute,
+}
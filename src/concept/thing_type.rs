@@ -0,0 +1,190 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::Stream;
+
+use super::{attribute::ValueType, AttributeType, EntityType, RelationType, RoleType, Thing, ThingType};
+use crate::{common::Result, Transaction};
+
+impl ThingType {
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Root(root_thing_type) => &root_thing_type.label,
+            Self::Entity(entity_type) => &entity_type.label,
+            Self::Relation(relation_type) => &relation_type.label,
+            Self::Attribute(attribute_type) => attribute_type.label(),
+        }
+    }
+
+    fn label_mut(&mut self) -> &mut String {
+        match self {
+            Self::Root(root_thing_type) => &mut root_thing_type.label,
+            Self::Entity(entity_type) => &mut entity_type.label,
+            Self::Relation(relation_type) => &mut relation_type.label,
+            Self::Attribute(attribute_type) => attribute_type.label_mut(),
+        }
+    }
+
+    pub async fn delete(&self, transaction: &Transaction<'_>) -> Result {
+        transaction.transaction_stream().thing_type_delete(self.clone()).await
+    }
+
+    pub async fn set_label(&mut self, transaction: &Transaction<'_>, new_label: &str) -> Result {
+        transaction.transaction_stream().thing_type_set_label(self.clone(), new_label.to_string()).await?;
+        *self.label_mut() = new_label.to_string();
+        Ok(())
+    }
+
+    pub async fn set_abstract(&self, transaction: &Transaction<'_>) -> Result {
+        transaction.transaction_stream().thing_type_set_abstract(self.clone()).await
+    }
+
+    pub async fn get_supertype(&self, transaction: &Transaction<'_>) -> Result<Option<ThingType>> {
+        transaction.transaction_stream().thing_type_get_supertype(self.clone()).await
+    }
+
+    pub fn get_supertypes(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<ThingType>>> {
+        transaction.transaction_stream().thing_type_get_supertypes(self.clone())
+    }
+
+    pub fn get_subtypes(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<ThingType>>> {
+        transaction.transaction_stream().thing_type_get_subtypes(self.clone())
+    }
+
+    pub fn get_instances(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<Thing>>> {
+        transaction.transaction_stream().thing_type_get_instances(self.clone())
+    }
+
+    pub fn get_owns(
+        &self,
+        transaction: &Transaction<'_>,
+        value_type: Option<ValueType>,
+        keys_only: bool,
+    ) -> Result<impl Stream<Item = Result<AttributeType>>> {
+        transaction.transaction_stream().thing_type_get_owns(self.clone(), value_type, keys_only)
+    }
+
+    pub fn get_plays(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<RoleType>>> {
+        transaction.transaction_stream().thing_type_get_plays(self.clone())
+    }
+}
+
+impl AttributeType {
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Root(attribute_type) => &attribute_type.label,
+            Self::Boolean(attribute_type) => &attribute_type.label,
+            Self::Long(attribute_type) => &attribute_type.label,
+            Self::Double(attribute_type) => &attribute_type.label,
+            Self::String(attribute_type) => &attribute_type.label,
+            Self::DateTime(attribute_type) => &attribute_type.label,
+        }
+    }
+
+    fn label_mut(&mut self) -> &mut String {
+        match self {
+            Self::Root(attribute_type) => &mut attribute_type.label,
+            Self::Boolean(attribute_type) => &mut attribute_type.label,
+            Self::Long(attribute_type) => &mut attribute_type.label,
+            Self::Double(attribute_type) => &mut attribute_type.label,
+            Self::String(attribute_type) => &mut attribute_type.label,
+            Self::DateTime(attribute_type) => &mut attribute_type.label,
+        }
+    }
+
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Root(_) => ValueType::Object,
+            Self::Boolean(_) => ValueType::Boolean,
+            Self::Long(_) => ValueType::Long,
+            Self::Double(_) => ValueType::Double,
+            Self::String(_) => ValueType::String,
+            Self::DateTime(_) => ValueType::DateTime,
+        }
+    }
+}
+
+macro_rules! thing_type_api {
+    { $( $type_:ident => $variant:ident ),+ $(,)? } => { $(
+        impl From<$type_> for ThingType {
+            fn from(thing_type: $type_) -> Self {
+                Self::$variant(thing_type)
+            }
+        }
+
+        impl $type_ {
+            pub async fn delete(&self, transaction: &Transaction<'_>) -> Result {
+                ThingType::from(self.clone()).delete(transaction).await
+            }
+
+            pub async fn set_label(&mut self, transaction: &Transaction<'_>, new_label: &str) -> Result {
+                let mut thing_type = ThingType::from(self.clone());
+                thing_type.set_label(transaction, new_label).await?;
+                if let ThingType::$variant(thing_type) = thing_type {
+                    *self = thing_type;
+                }
+                Ok(())
+            }
+
+            pub async fn set_abstract(&self, transaction: &Transaction<'_>) -> Result {
+                ThingType::from(self.clone()).set_abstract(transaction).await
+            }
+
+            pub async fn get_supertype(&self, transaction: &Transaction<'_>) -> Result<Option<ThingType>> {
+                ThingType::from(self.clone()).get_supertype(transaction).await
+            }
+
+            pub fn get_supertypes(
+                &self,
+                transaction: &Transaction<'_>,
+            ) -> Result<impl Stream<Item = Result<ThingType>>> {
+                ThingType::from(self.clone()).get_supertypes(transaction)
+            }
+
+            pub fn get_subtypes(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<ThingType>>> {
+                ThingType::from(self.clone()).get_subtypes(transaction)
+            }
+
+            pub fn get_instances(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<Thing>>> {
+                ThingType::from(self.clone()).get_instances(transaction)
+            }
+
+            pub fn get_owns(
+                &self,
+                transaction: &Transaction<'_>,
+                value_type: Option<ValueType>,
+                keys_only: bool,
+            ) -> Result<impl Stream<Item = Result<AttributeType>>> {
+                ThingType::from(self.clone()).get_owns(transaction, value_type, keys_only)
+            }
+
+            pub fn get_plays(&self, transaction: &Transaction<'_>) -> Result<impl Stream<Item = Result<RoleType>>> {
+                ThingType::from(self.clone()).get_plays(transaction)
+            }
+        }
+    )+ };
+}
+
+thing_type_api! {
+    EntityType => Entity,
+    RelationType => Relation,
+    AttributeType => Attribute,
+}
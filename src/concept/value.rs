@@ -0,0 +1,78 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::convert::TryFrom;
+
+use chrono::NaiveDateTime;
+
+use super::{attribute::ValueType, Concept, Thing, Type, Value};
+use crate::common::{error::ConceptError, Error, Result};
+
+impl Value {
+    pub fn value_type(&self) -> ValueType {
+        match self {
+            Self::Boolean(_) => ValueType::Boolean,
+            Self::Long(_) => ValueType::Long,
+            Self::Double(_) => ValueType::Double,
+            Self::String(_) => ValueType::String,
+            Self::DateTime(_) => ValueType::DateTime,
+        }
+    }
+}
+
+impl TryFrom<&Concept> for Value {
+    type Error = Error;
+
+    fn try_from(concept: &Concept) -> Result<Self> {
+        match concept {
+            Concept::Thing(Thing::Attribute(attribute)) => Ok(attribute.value()),
+            Concept::Thing(Thing::Entity(_)) => Err(ConceptError::UnexpectedConceptKind("attribute", "entity").into()),
+            Concept::Thing(Thing::Relation(_)) => {
+                Err(ConceptError::UnexpectedConceptKind("attribute", "relation").into())
+            }
+            Concept::Type(Type::Thing(_)) => Err(ConceptError::UnexpectedConceptKind("attribute", "thing type").into()),
+            Concept::Type(Type::Role(_)) => Err(ConceptError::UnexpectedConceptKind("attribute", "role type").into()),
+        }
+    }
+}
+
+macro_rules! value_try_from_concept {
+    { $( $target:ty => $variant:ident ),+ $(,)? } => { $(
+        impl TryFrom<&Concept> for $target {
+            type Error = Error;
+
+            fn try_from(concept: &Concept) -> Result<Self> {
+                match Value::try_from(concept)? {
+                    Value::$variant(value) => Ok(value),
+                    other => Err(ConceptError::UnexpectedValueType(ValueType::$variant, other.value_type()).into()),
+                }
+            }
+        }
+    )+ };
+}
+
+value_try_from_concept! {
+    bool => Boolean,
+    i64 => Long,
+    f64 => Double,
+    String => String,
+    NaiveDateTime => DateTime,
+}
@@ -20,19 +20,23 @@
  */
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::Entry, HashMap, HashSet},
     fmt,
-    sync::{Arc, Mutex},
+    sync::{Arc, Mutex, RwLock},
     time::Duration,
 };
 
+use futures::future::join_all;
 use itertools::Itertools;
+use log::{debug, warn};
 use tokio::{
     select,
     sync::mpsc::{unbounded_channel as unbounded_async, UnboundedReceiver, UnboundedSender},
-    time::{sleep_until, Instant},
+    time::{sleep, sleep_until, timeout, Instant},
 };
 
+#[cfg(feature = "tracing")]
+use super::trace::record_transaction_open;
 use super::{
     network::transmitter::{RPCTransmitter, TransactionTransmitter},
     runtime::BackgroundRuntime,
@@ -42,52 +46,101 @@ use crate::{
     common::{
         address::Address,
         error::{ConnectionError, Error},
-        info::{DatabaseInfo, SessionInfo},
-        Result, SessionID, SessionType, TransactionType,
+        info::{DatabaseInfo, ServerStatus, SessionInfo, UserInfo},
+        metrics, Result, SessionID, SessionType, TransactionType,
     },
     connection::message::{Request, Response, TransactionRequest},
     error::InternalError,
-    Credential, Options,
+    ConnectionSettings, Credential, Options,
 };
 
 #[derive(Clone)]
 pub struct Connection {
-    server_connections: HashMap<Address, ServerConnection>,
+    server_connections: Arc<RwLock<HashMap<Address, ServerConnection>>>,
+    server_absences: Arc<Mutex<HashMap<Address, usize>>>,
     background_runtime: Arc<BackgroundRuntime>,
+    credential: Option<Credential>,
+    settings: ConnectionSettings,
 }
 
 impl Connection {
+    const SERVER_REMOVAL_ABSENCES: usize = 3;
+    const SERVER_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
     pub fn new_plaintext(address: impl AsRef<str>) -> Result<Self> {
+        Self::new_plaintext_with_settings(address, ConnectionSettings::default())
+    }
+
+    pub fn new_plaintext_with_settings(address: impl AsRef<str>, settings: ConnectionSettings) -> Result<Self> {
         let address: Address = address.as_ref().parse()?;
         let background_runtime = Arc::new(BackgroundRuntime::new()?);
-        let server_connection = ServerConnection::new_plaintext(background_runtime.clone(), address.clone())?;
-        Ok(Self { server_connections: [(address, server_connection)].into(), background_runtime })
+        let server_connection =
+            ServerConnection::new_plaintext(background_runtime.clone(), address.clone(), settings.clone())?;
+        Ok(Self {
+            server_connections: Arc::new(RwLock::new([(address, server_connection)].into())),
+            server_absences: Default::default(),
+            background_runtime,
+            credential: None,
+            settings,
+        })
     }
 
     pub fn new_encrypted<T: AsRef<str> + Sync>(init_addresses: &[T], credential: Credential) -> Result<Self> {
+        Self::new_encrypted_with_settings(init_addresses, credential, ConnectionSettings::default())
+    }
+
+    pub fn new_encrypted_with_settings<T: AsRef<str> + Sync>(
+        init_addresses: &[T],
+        credential: Credential,
+        settings: ConnectionSettings,
+    ) -> Result<Self> {
         let background_runtime = Arc::new(BackgroundRuntime::new()?);
 
         let init_addresses = init_addresses.iter().map(|addr| addr.as_ref().parse()).try_collect()?;
-        let addresses = Self::fetch_current_addresses(background_runtime.clone(), init_addresses, credential.clone())?;
+        let addresses = Self::fetch_current_addresses(
+            background_runtime.clone(),
+            init_addresses,
+            credential.clone(),
+            settings.clone(),
+        )?;
 
         let mut server_connections = HashMap::with_capacity(addresses.len());
         for address in addresses {
-            let server_connection =
-                ServerConnection::new_encrypted(background_runtime.clone(), address.clone(), credential.clone())?;
+            let server_connection = ServerConnection::new_encrypted(
+                background_runtime.clone(),
+                address.clone(),
+                credential.clone(),
+                settings.clone(),
+            )?;
             server_connections.insert(address, server_connection);
         }
 
-        Ok(Self { server_connections, background_runtime })
+        let connection = Self {
+            server_connections: Arc::new(RwLock::new(server_connections)),
+            server_absences: Default::default(),
+            background_runtime,
+            credential: Some(credential),
+            settings,
+        };
+        if let Some(interval) = connection.settings.server_refresh_interval {
+            connection.start_server_refresh(interval);
+        }
+        Ok(connection)
     }
 
     fn fetch_current_addresses(
         background_runtime: Arc<BackgroundRuntime>,
         addresses: Vec<Address>,
         credential: Credential,
+        settings: ConnectionSettings,
     ) -> Result<HashSet<Address>> {
         for address in addresses {
-            let server_connection =
-                ServerConnection::new_encrypted(background_runtime.clone(), address.clone(), credential.clone());
+            let server_connection = ServerConnection::new_encrypted(
+                background_runtime.clone(),
+                address.clone(),
+                credential.clone(),
+                settings.clone(),
+            );
             match server_connection {
                 Ok(server_connection) => match server_connection.servers_all() {
                     Ok(servers) => return Ok(servers.into_iter().collect()),
@@ -101,32 +154,222 @@ impl Connection {
         Err(ConnectionError::UnableToConnect())?
     }
 
+    pub fn refresh_servers(&self) -> Result {
+        let connection = self.clone();
+        self.background_runtime.run_blocking(async move { connection.refresh_servers_async().await })
+    }
+
+    pub(crate) async fn refresh_servers_async(&self) -> Result {
+        let credential = match &self.credential {
+            Some(credential) => credential,
+            None => return Ok(()),
+        };
+
+        let addresses = self.fetch_server_addresses().await?;
+        let known_addresses: HashSet<Address> = self.addresses().into_iter().collect();
+        let mut added = HashMap::new();
+        for address in addresses.difference(&known_addresses) {
+            match ServerConnection::new_encrypted_async(
+                self.background_runtime.clone(),
+                address.clone(),
+                credential.clone(),
+                self.settings.clone(),
+            )
+            .await
+            {
+                Ok(server_connection) => {
+                    added.insert(address.clone(), server_connection);
+                }
+                Err(Error::Connection(ConnectionError::UnableToConnect())) => {
+                    debug!("Unable to connect to new server {}, it will be retried on the next refresh.", address);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        let (duplicates, removed): (Vec<ServerConnection>, Vec<ServerConnection>) = {
+            let mut server_connections = self.server_connections.write().unwrap();
+            let mut server_absences = self.server_absences.lock().unwrap();
+            // A concurrent refresh may have connected to the same server meanwhile, and its connection may already
+            // have open sessions, so it is kept and ours is closed instead.
+            let duplicates = added
+                .into_iter()
+                .filter_map(|(address, server_connection)| match server_connections.entry(address) {
+                    Entry::Occupied(_) => Some(server_connection),
+                    Entry::Vacant(entry) => {
+                        entry.insert(server_connection);
+                        None
+                    }
+                })
+                .collect();
+            server_absences.retain(|address, _| !addresses.contains(address));
+            // A server missing from a single reply may only be restarting, and one that still has open sessions
+            // is kept until they are closed.
+            let stale_addresses: Vec<Address> = server_connections
+                .iter()
+                .filter(|(address, _)| !addresses.contains(*address))
+                .filter(|(address, server_connection)| {
+                    let absences = server_absences.entry((*address).clone()).or_default();
+                    *absences += 1;
+                    *absences >= Self::SERVER_REMOVAL_ABSENCES && !server_connection.has_open_sessions()
+                })
+                .map(|(address, _)| address.clone())
+                .collect();
+            let removed = stale_addresses
+                .iter()
+                .filter_map(|address| {
+                    server_absences.remove(address);
+                    server_connections.remove(address)
+                })
+                .collect();
+            (duplicates, removed)
+        };
+        for server_connection in duplicates {
+            server_connection.force_close().ok();
+        }
+        for server_connection in removed {
+            debug!("Server {} has left the cluster, closing its connection.", server_connection.address());
+            server_connection.force_close().ok();
+        }
+        Ok(())
+    }
+
+    async fn fetch_server_addresses(&self) -> Result<HashSet<Address>> {
+        for server_connection in self.connections() {
+            match server_connection.servers_all_async().await {
+                Ok(servers) => return Ok(servers.into_iter().collect()),
+                Err(Error::Connection(ConnectionError::UnableToConnect())) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        Err(self.unable_to_connect_error())
+    }
+
+    pub async fn servers_status(&self) -> Result<Vec<ServerStatus>> {
+        let mut addresses: HashSet<Address> = self.addresses().into_iter().collect();
+        if self.is_encrypted() {
+            for server_connection in self.connections() {
+                match server_connection.servers_all_async().await {
+                    Ok(servers) => {
+                        addresses.extend(servers);
+                        break;
+                    }
+                    Err(Error::Connection(ConnectionError::UnableToConnect())) => (),
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+
+        let probe_timeout = self.settings.request_timeout.unwrap_or(Self::SERVER_PROBE_TIMEOUT);
+        let statuses = addresses.into_iter().sorted_by_key(Address::to_string).map(|address| async move {
+            let probe = match self.connection(&address) {
+                Ok(server_connection) => {
+                    // Probes run on the background runtime, so that they time out whichever executor awaits them.
+                    self.background_runtime
+                        .run(async move { timeout(probe_timeout, server_connection.probe()).await })
+                        .await
+                }
+                Err(err) => Ok(Err(err)),
+            };
+            let (is_reachable, latency, primary_databases) = match probe {
+                Ok(Ok((databases, latency))) => (
+                    true,
+                    Some(latency),
+                    databases
+                        .into_iter()
+                        .filter(|database| {
+                            database.replicas.iter().any(|replica| replica.is_primary && replica.address == address)
+                        })
+                        .map(|database| database.name)
+                        .collect(),
+                ),
+                Ok(Err(err)) => {
+                    debug!("Health probe of {} failed: {}", address, err);
+                    (false, None, Vec::new())
+                }
+                Err(_) => {
+                    debug!("Health probe of {} timed out after {:?}", address, probe_timeout);
+                    (false, None, Vec::new())
+                }
+            };
+            ServerStatus { address: address.to_string(), is_reachable, latency, primary_databases }
+        });
+        Ok(join_all(statuses).await)
+    }
+
+    fn start_server_refresh(&self, interval: Duration) {
+        // Only weak references are held, so the refresh stops once the connection is dropped.
+        let server_connections = Arc::downgrade(&self.server_connections);
+        let server_absences = Arc::downgrade(&self.server_absences);
+        let background_runtime = Arc::downgrade(&self.background_runtime);
+        let credential = self.credential.clone();
+        let settings = self.settings.clone();
+        self.background_runtime.spawn(async move {
+            loop {
+                sleep(interval).await;
+                let connection =
+                    match (server_connections.upgrade(), server_absences.upgrade(), background_runtime.upgrade()) {
+                        (Some(server_connections), Some(server_absences), Some(background_runtime))
+                            if background_runtime.is_open() =>
+                        {
+                            Connection {
+                                server_connections,
+                                server_absences,
+                                background_runtime,
+                                credential: credential.clone(),
+                                settings: settings.clone(),
+                            }
+                        }
+                        _ => break,
+                    };
+                if let Err(err) = connection.refresh_servers_async().await {
+                    warn!("Failed to refresh cluster servers: {}", err);
+                }
+            }
+        });
+    }
+
     pub fn force_close(self) -> Result {
-        self.server_connections.values().map(ServerConnection::force_close).try_collect()?;
+        self.connections().iter().map(ServerConnection::force_close).try_collect()?;
         self.background_runtime.force_close()
     }
 
+    pub(crate) fn is_encrypted(&self) -> bool {
+        self.credential.is_some()
+    }
+
+    pub(crate) fn settings(&self) -> &ConnectionSettings {
+        &self.settings
+    }
+
+    pub(crate) async fn sleep(&self, duration: Duration) {
+        self.background_runtime.sleep(duration).await
+    }
+
     pub(crate) fn server_count(&self) -> usize {
-        self.server_connections.len()
+        self.server_connections.read().unwrap().len()
     }
 
-    pub(crate) fn addresses(&self) -> impl Iterator<Item = &Address> {
-        self.server_connections.keys()
+    pub(crate) fn addresses(&self) -> Vec<Address> {
+        self.server_connections.read().unwrap().keys().cloned().collect()
     }
 
-    pub(crate) fn connection(&self, address: &Address) -> Result<&ServerConnection> {
+    pub(crate) fn connection(&self, address: &Address) -> Result<ServerConnection> {
         self.server_connections
+            .read()
+            .unwrap()
             .get(address)
+            .cloned()
             .ok_or_else(|| InternalError::UnknownConnectionAddress(address.to_string()).into())
     }
 
-    pub(crate) fn connections(&self) -> impl Iterator<Item = &ServerConnection> + '_ {
-        self.server_connections.values()
+    pub(crate) fn connections(&self) -> Vec<ServerConnection> {
+        self.server_connections.read().unwrap().values().cloned().collect()
     }
 
     pub(crate) fn unable_to_connect_error(&self) -> Error {
         Error::Connection(ConnectionError::ClusterUnableToConnect(
-            self.addresses().map(Address::to_string).collect::<Vec<_>>().join(","),
+            self.addresses().iter().map(Address::to_string).collect::<Vec<_>>().join(","),
         ))
     }
 }
@@ -141,24 +384,43 @@ impl fmt::Debug for Connection {
 pub(crate) struct ServerConnection {
     address: Address,
     background_runtime: Arc<BackgroundRuntime>,
-    open_sessions: Arc<Mutex<HashMap<SessionID, UnboundedSender<()>>>>,
+    open_sessions: Arc<Mutex<HashMap<SessionID, OpenSession>>>,
     request_transmitter: Arc<RPCTransmitter>,
+    settings: ConnectionSettings,
 }
 
 impl ServerConnection {
-    fn new_plaintext(background_runtime: Arc<BackgroundRuntime>, address: Address) -> Result<Self> {
-        let request_transmitter = Arc::new(RPCTransmitter::start_plaintext(address.clone(), &background_runtime)?);
-        Ok(Self { address, background_runtime, open_sessions: Default::default(), request_transmitter })
+    fn new_plaintext(
+        background_runtime: Arc<BackgroundRuntime>,
+        address: Address,
+        settings: ConnectionSettings,
+    ) -> Result<Self> {
+        let request_transmitter =
+            Arc::new(RPCTransmitter::start_plaintext(address.clone(), &settings, &background_runtime)?);
+        Ok(Self { address, background_runtime, open_sessions: Default::default(), request_transmitter, settings })
     }
 
     fn new_encrypted(
         background_runtime: Arc<BackgroundRuntime>,
         address: Address,
         credential: Credential,
+        settings: ConnectionSettings,
     ) -> Result<Self> {
         let request_transmitter =
-            Arc::new(RPCTransmitter::start_encrypted(address.clone(), credential, &background_runtime)?);
-        Ok(Self { address, background_runtime, open_sessions: Default::default(), request_transmitter })
+            Arc::new(RPCTransmitter::start_encrypted(address.clone(), credential, &settings, &background_runtime)?);
+        Ok(Self { address, background_runtime, open_sessions: Default::default(), request_transmitter, settings })
+    }
+
+    async fn new_encrypted_async(
+        background_runtime: Arc<BackgroundRuntime>,
+        address: Address,
+        credential: Credential,
+        settings: ConnectionSettings,
+    ) -> Result<Self> {
+        let request_transmitter = Arc::new(
+            RPCTransmitter::start_encrypted_async(address.clone(), credential, &settings, &background_runtime).await?,
+        );
+        Ok(Self { address, background_runtime, open_sessions: Default::default(), request_transmitter, settings })
     }
 
     pub(crate) fn address(&self) -> &Address {
@@ -194,6 +456,19 @@ impl ServerConnection {
         }
     }
 
+    pub(crate) async fn servers_all_async(&self) -> Result<Vec<Address>> {
+        match self.request_async(Request::ServersAll).await? {
+            Response::ServersAll { servers } => Ok(servers),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn probe(&self) -> Result<(Vec<DatabaseInfo>, Duration)> {
+        let start = Instant::now();
+        let databases = self.all_databases().await?;
+        Ok((databases, start.elapsed()))
+    }
+
     pub(crate) async fn database_exists(&self, database_name: String) -> Result<bool> {
         match self.request_async(Request::DatabasesContains { database_name }).await? {
             Response::DatabasesContains { contains } => Ok(contains),
@@ -246,6 +521,52 @@ impl ServerConnection {
         Ok(())
     }
 
+    pub(crate) async fn user_exists(&self, username: String) -> Result<bool> {
+        match self.request_async(Request::UsersContains { username }).await? {
+            Response::UsersContains { contains } => Ok(contains),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn create_user(&self, username: String, password: String) -> Result {
+        self.request_async(Request::UsersCreate { username, password }).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn delete_user(&self, username: String) -> Result {
+        self.request_async(Request::UsersDelete { username }).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn all_users(&self) -> Result<Vec<UserInfo>> {
+        match self.request_async(Request::UsersAll).await? {
+            Response::UsersAll { users } => Ok(users),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn get_user(&self, username: String) -> Result<Option<UserInfo>> {
+        match self.request_async(Request::UsersGet { username }).await? {
+            Response::UsersGet { user } => Ok(user),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn set_user_password(&self, username: String, password: String) -> Result {
+        self.request_async(Request::UsersPasswordSet { username, password }).await?;
+        Ok(())
+    }
+
+    pub(crate) async fn update_user_password(
+        &self,
+        username: String,
+        password_old: String,
+        password_new: String,
+    ) -> Result {
+        self.request_async(Request::UserPasswordUpdate { username, password_old, password_new }).await?;
+        Ok(())
+    }
+
     pub(crate) async fn open_session(
         &self,
         database_name: String,
@@ -255,26 +576,41 @@ impl ServerConnection {
         let start = Instant::now();
         match self.request_async(Request::SessionOpen { database_name, session_type, options }).await? {
             Response::SessionOpen { session_id, server_duration } => {
+                let network_latency = start.elapsed() - server_duration;
                 let (pulse_shutdown_sink, pulse_shutdown_source) = unbounded_async();
-                self.open_sessions.lock().unwrap().insert(session_id.clone(), pulse_shutdown_sink);
+                self.open_sessions
+                    .lock()
+                    .unwrap()
+                    .insert(session_id.clone(), OpenSession { pulse_shutdown_sink, on_close: Vec::new() });
+                metrics::session_opened();
                 self.background_runtime.spawn(session_pulse(
                     session_id.clone(),
                     self.request_transmitter.clone(),
+                    self.open_sessions.clone(),
+                    self.settings.session_pulse_interval,
                     pulse_shutdown_source,
                 ));
-                Ok(SessionInfo {
-                    address: self.address.clone(),
-                    session_id,
-                    network_latency: start.elapsed() - server_duration,
-                })
+                Ok(SessionInfo { address: self.address.clone(), session_id, network_latency })
             }
             other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
         }
     }
 
+    pub(crate) fn on_session_close(&self, session_id: &SessionID, callback: impl FnOnce(Error) + Send + 'static) {
+        match self.open_sessions.lock().unwrap().get_mut(session_id) {
+            Some(open_session) => open_session.on_close.push(Box::new(callback)),
+            None => callback(ConnectionError::SessionIsClosed().into()),
+        }
+    }
+
+    pub(crate) fn has_open_sessions(&self) -> bool {
+        !self.open_sessions.lock().unwrap().is_empty()
+    }
+
     pub(crate) fn close_session(&self, session_id: SessionID) -> Result {
-        if let Some(sink) = self.open_sessions.lock().unwrap().remove(&session_id) {
-            sink.send(()).ok();
+        if let Some(open_session) = self.open_sessions.lock().unwrap().remove(&session_id) {
+            open_session.pulse_shutdown_sink.send(()).ok();
+            metrics::session_closed();
         }
         self.request_blocking(Request::SessionClose { session_id })?;
         Ok(())
@@ -287,6 +623,8 @@ impl ServerConnection {
         options: Options,
         network_latency: Duration,
     ) -> Result<TransactionStream> {
+        #[cfg(feature = "tracing")]
+        record_transaction_open(&session_id, &self.address);
         match self
             .request_async(Request::Transaction(TransactionRequest::Open {
                 session_id,
@@ -297,7 +635,12 @@ impl ServerConnection {
             .await?
         {
             Response::TransactionOpen { request_sink, response_source } => {
-                let transmitter = TransactionTransmitter::new(&self.background_runtime, request_sink, response_source);
+                let transmitter = TransactionTransmitter::new(
+                    &self.background_runtime,
+                    request_sink,
+                    response_source,
+                    &self.settings,
+                );
                 Ok(TransactionStream::new(transaction_type, options, transmitter))
             }
             other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
@@ -309,26 +652,48 @@ impl fmt::Debug for ServerConnection {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ServerConnection")
             .field("address", &self.address)
-            .field("open_sessions", &self.open_sessions)
+            .field("open_sessions", &self.open_sessions.lock().unwrap().keys().collect::<Vec<_>>())
             .finish()
     }
 }
 
+struct OpenSession {
+    pulse_shutdown_sink: UnboundedSender<()>,
+    on_close: Vec<Box<dyn FnOnce(Error) + Send>>,
+}
+
 async fn session_pulse(
     session_id: SessionID,
     request_transmitter: Arc<RPCTransmitter>,
+    open_sessions: Arc<Mutex<HashMap<SessionID, OpenSession>>>,
+    pulse_interval: Duration,
     mut shutdown_source: UnboundedReceiver<()>,
 ) {
-    const PULSE_INTERVAL: Duration = Duration::from_secs(5);
     let mut next_pulse = Instant::now();
     loop {
         select! {
             _ = sleep_until(next_pulse) => {
-                request_transmitter
+                let close_reason = match request_transmitter
                     .request_async(Request::SessionPulse { session_id: session_id.clone() })
                     .await
-                    .ok();
-                next_pulse += PULSE_INTERVAL;
+                {
+                    Ok(Response::SessionPulse { is_alive: false }) => ConnectionError::SessionIsClosed().into(),
+                    Err(err @ Error::Server(_)) => err,
+                    _ => {
+                        next_pulse += pulse_interval;
+                        continue;
+                    }
+                };
+                // The server no longer knows this session: nothing is left to close remotely.
+                let open_session = open_sessions.lock().unwrap().remove(&session_id);
+                if let Some(open_session) = open_session {
+                    debug!("Session {session_id} was closed by the server: {close_reason}");
+                    metrics::session_closed();
+                    for callback in open_session.on_close {
+                        callback(close_reason.clone());
+                    }
+                }
+                break;
             }
             _ = shutdown_source.recv() => break,
         }
@@ -24,10 +24,20 @@ use std::time::Duration;
 use tokio::sync::mpsc::UnboundedSender;
 use tonic::Streaming;
 use typedb_protocol::transaction;
+use typeql_lang::pattern::{Conjunction, ThingVariable};
 
 use crate::{
-    answer::{ConceptMap, Numeric},
-    common::{address::Address, info::DatabaseInfo, RequestID, SessionID},
+    answer::{ConceptMap, ConceptMapGroup, Numeric, NumericGroup},
+    common::{
+        address::Address,
+        info::{DatabaseInfo, UserInfo},
+        RequestID, SessionID,
+    },
+    concept::{
+        attribute::ValueType, Attribute, AttributeType, EntityType, Relation, RelationType, RoleType, SchemaException,
+        Thing, ThingType,
+    },
+    logic::{Explanation, Rule},
     Options, SessionType, TransactionType,
 };
 
@@ -50,6 +60,42 @@ pub(super) enum Request {
     SessionPulse { session_id: SessionID },
 
     Transaction(TransactionRequest),
+
+    UsersContains { username: String },
+    UsersCreate { username: String, password: String },
+    UsersDelete { username: String },
+    UsersAll,
+    UsersGet { username: String },
+    UsersPasswordSet { username: String, password: String },
+
+    UserPasswordUpdate { username: String, password_old: String, password_new: String },
+}
+
+impl Request {
+    pub(super) fn name(&self) -> &'static str {
+        match self {
+            Request::ServersAll => "servers_all",
+            Request::DatabasesContains { .. } => "databases_contains",
+            Request::DatabaseCreate { .. } => "database_create",
+            Request::DatabaseGet { .. } => "database_get",
+            Request::DatabasesAll => "databases_all",
+            Request::DatabaseSchema { .. } => "database_schema",
+            Request::DatabaseTypeSchema { .. } => "database_type_schema",
+            Request::DatabaseRuleSchema { .. } => "database_rule_schema",
+            Request::DatabaseDelete { .. } => "database_delete",
+            Request::SessionOpen { .. } => "session_open",
+            Request::SessionClose { .. } => "session_close",
+            Request::SessionPulse { .. } => "session_pulse",
+            Request::Transaction(_) => "transaction",
+            Request::UsersContains { .. } => "users_contains",
+            Request::UsersCreate { .. } => "users_create",
+            Request::UsersDelete { .. } => "users_delete",
+            Request::UsersAll => "users_all",
+            Request::UsersGet { .. } => "users_get",
+            Request::UsersPasswordSet { .. } => "users_password_set",
+            Request::UserPasswordUpdate { .. } => "user_password_update",
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -84,13 +130,30 @@ pub(super) enum Response {
         session_id: SessionID,
         server_duration: Duration,
     },
-    SessionPulse,
+    SessionPulse {
+        is_alive: bool,
+    },
     SessionClose,
 
     TransactionOpen {
         request_sink: UnboundedSender<transaction::Client>,
         response_source: Streaming<transaction::Server>,
     },
+
+    UsersContains {
+        contains: bool,
+    },
+    UsersCreate,
+    UsersDelete,
+    UsersAll {
+        users: Vec<UserInfo>,
+    },
+    UsersGet {
+        user: Option<UserInfo>,
+    },
+    UsersPasswordSet,
+
+    UserPasswordUpdate,
 }
 
 #[derive(Debug)]
@@ -99,6 +162,11 @@ pub(super) enum TransactionRequest {
     Commit,
     Rollback,
     Query(QueryRequest),
+    Concept(ConceptRequest),
+    ThingType(ThingTypeRequest),
+    Thing(ThingRequest),
+    Logic(LogicRequest),
+    Rule(RuleRequest),
     Stream { request_id: RequestID },
 }
 
@@ -108,6 +176,11 @@ pub(super) enum TransactionResponse {
     Commit,
     Rollback,
     Query(QueryResponse),
+    Concept(ConceptResponse),
+    ThingType(ThingTypeResponse),
+    Thing(ThingResponse),
+    Logic(LogicResponse),
+    Rule(RuleResponse),
 }
 
 #[derive(Debug)]
@@ -140,8 +213,139 @@ pub(super) enum QueryResponse {
 
     MatchAggregate { answer: Numeric },
 
-    Explain {}, // TODO: explanations
+    Explain { answers: Vec<Explanation> },
+
+    MatchGroup { answers: Vec<ConceptMapGroup> },
+    MatchGroupAggregate { answers: Vec<NumericGroup> },
+}
+
+impl QueryResponse {
+    pub(super) fn answer_count(&self) -> usize {
+        match self {
+            QueryResponse::Define | QueryResponse::Undefine | QueryResponse::Delete => 0,
+            QueryResponse::Match { answers }
+            | QueryResponse::Insert { answers }
+            | QueryResponse::Update { answers } => answers.len(),
+            QueryResponse::MatchAggregate { .. } => 1,
+            QueryResponse::Explain { answers } => answers.len(),
+            QueryResponse::MatchGroup { answers } => answers.len(),
+            QueryResponse::MatchGroupAggregate { answers } => answers.len(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(super) enum ConceptRequest {
+    GetThingType { label: String },
+    GetThing { iid: Vec<u8> },
+
+    PutEntityType { label: String },
+    PutRelationType { label: String },
+    PutAttributeType { label: String, value_type: ValueType },
+
+    GetSchemaExceptions,
+}
+
+#[derive(Debug)]
+pub(super) enum ConceptResponse {
+    GetThingType { thing_type: Option<ThingType> },
+    GetThing { thing: Option<Thing> },
+
+    PutEntityType { entity_type: EntityType },
+    PutRelationType { relation_type: RelationType },
+    PutAttributeType { attribute_type: AttributeType },
+
+    GetSchemaExceptions { exceptions: Vec<SchemaException> },
+}
+
+#[derive(Debug)]
+pub(super) enum ThingTypeRequest {
+    Delete { thing_type: ThingType },
+    SetLabel { thing_type: ThingType, new_label: String },
+    SetAbstract { thing_type: ThingType },
+
+    GetSupertype { thing_type: ThingType },
+    GetSupertypes { thing_type: ThingType },
+    GetSubtypes { thing_type: ThingType },
+
+    GetInstances { thing_type: ThingType },
+    GetOwns { thing_type: ThingType, value_type: Option<ValueType>, keys_only: bool },
+    GetPlays { thing_type: ThingType },
+}
+
+#[derive(Debug)]
+pub(super) enum ThingTypeResponse {
+    Delete,
+    SetLabel,
+    SetAbstract,
+
+    GetSupertype { supertype: Option<ThingType> },
+    GetSupertypes { supertypes: Vec<ThingType> },
+    GetSubtypes { subtypes: Vec<ThingType> },
+
+    GetInstances { instances: Vec<Thing> },
+    GetOwns { attribute_types: Vec<AttributeType> },
+    GetPlays { role_types: Vec<RoleType> },
+}
+
+#[derive(Debug)]
+pub(super) enum ThingRequest {
+    Delete { iid: Vec<u8> },
+
+    GetHas { iid: Vec<u8>, attribute_types: Vec<AttributeType>, keys_only: bool },
+    SetHas { iid: Vec<u8>, attribute: Attribute },
+    UnsetHas { iid: Vec<u8>, attribute: Attribute },
+
+    GetRelations { iid: Vec<u8>, role_types: Vec<RoleType> },
+    GetPlaying { iid: Vec<u8> },
+
+    RelationGetPlayersByRoleType { iid: Vec<u8> },
+    RelationAddPlayer { iid: Vec<u8>, role_type: RoleType, player: Thing },
+    RelationRemovePlayer { iid: Vec<u8>, role_type: RoleType, player: Thing },
+
+    AttributeGetOwners { iid: Vec<u8>, thing_type: Option<ThingType> },
+}
+
+#[derive(Debug)]
+pub(super) enum ThingResponse {
+    Delete,
+
+    GetHas { attributes: Vec<Attribute> },
+    SetHas,
+    UnsetHas,
+
+    GetRelations { relations: Vec<Relation> },
+    GetPlaying { role_types: Vec<RoleType> },
+
+    RelationGetPlayersByRoleType { role_types_with_players: Vec<(RoleType, Thing)> },
+    RelationAddPlayer,
+    RelationRemovePlayer,
+
+    AttributeGetOwners { owners: Vec<Thing> },
+}
+
+#[derive(Debug)]
+pub(super) enum LogicRequest {
+    GetRule { label: String },
+    PutRule { label: String, when: Conjunction, then: ThingVariable },
+    GetRules,
+}
+
+#[derive(Debug)]
+pub(super) enum LogicResponse {
+    GetRule { rule: Option<Rule> },
+    PutRule { rule: Rule },
+    GetRules { rules: Vec<Rule> },
+}
+
+#[derive(Debug)]
+pub(super) enum RuleRequest {
+    Delete { label: String },
+    SetLabel { current_label: String, new_label: String },
+}
 
-    MatchGroup {},          // TODO: ConceptMapGroup
-    MatchGroupAggregate {}, // TODO: NumericGroup
+#[derive(Debug)]
+pub(super) enum RuleResponse {
+    Delete,
+    SetLabel,
 }
@@ -23,7 +23,9 @@ mod connection;
 mod message;
 mod network;
 mod runtime;
+#[cfg(feature = "tracing")]
+pub(crate) mod trace;
 mod transaction_stream;
 
-pub use self::connection::Connection;
+pub use self::{connection::Connection, transaction_stream::QueryHandle};
 pub(crate) use self::{connection::ServerConnection, transaction_stream::TransactionStream};
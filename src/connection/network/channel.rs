@@ -24,26 +24,23 @@ use std::sync::{Arc, RwLock};
 use tonic::{
     body::BoxBody,
     client::GrpcService,
-    service::{
-        interceptor::{self, InterceptedService},
-        Interceptor,
-    },
-    transport::{channel, Channel, Error as TonicError},
+    service::{interceptor::InterceptedService, Interceptor},
+    transport::{Channel, Endpoint, Error as TonicError},
     Request, Status,
 };
 
+#[cfg(feature = "tracing")]
+use crate::connection::trace::inject_context;
 use crate::{
     common::{address::Address, Result, StdResult},
-    Credential,
+    ConnectionSettings, Credential,
 };
 
-type ResponseFuture = interceptor::ResponseFuture<channel::ResponseFuture>;
-
 pub(super) type PlainTextChannel = InterceptedService<Channel, PlainTextFacade>;
 pub(super) type CallCredChannel = InterceptedService<Channel, CredentialInjector>;
 
 pub(super) trait GRPCChannel:
-    GrpcService<BoxBody, Error = TonicError, ResponseBody = BoxBody, Future = ResponseFuture> + Clone + Send + 'static
+    GrpcService<BoxBody, Error = TonicError, ResponseBody = BoxBody> + Clone + Send + 'static
 {
     fn is_plaintext(&self) -> bool;
 }
@@ -60,8 +57,22 @@ impl GRPCChannel for CallCredChannel {
     }
 }
 
-pub(super) fn open_plaintext_channel(address: Address) -> PlainTextChannel {
-    PlainTextChannel::new(Channel::builder(address.into_uri()).connect_lazy(), PlainTextFacade)
+pub(super) fn open_plaintext_channel(address: Address, settings: &ConnectionSettings) -> PlainTextChannel {
+    PlainTextChannel::new(configure_endpoint(address, settings).connect_lazy(), PlainTextFacade)
+}
+
+fn configure_endpoint(address: Address, settings: &ConnectionSettings) -> Endpoint {
+    let mut builder = Channel::builder(address.into_uri());
+    if let Some(timeout) = settings.connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+    if let Some(timeout) = settings.request_timeout {
+        builder = builder.timeout(timeout);
+    }
+    if let Some(interval) = settings.keepalive_interval {
+        builder = builder.http2_keep_alive_interval(interval).keep_alive_while_idle(true);
+    }
+    builder
 }
 
 #[derive(Clone, Debug)]
@@ -69,15 +80,16 @@ pub(super) struct PlainTextFacade;
 
 impl Interceptor for PlainTextFacade {
     fn call(&mut self, request: Request<()>) -> StdResult<Request<()>, Status> {
-        Ok(request)
+        Ok(with_trace_context(request))
     }
 }
 
 pub(super) fn open_encrypted_channel(
     address: Address,
     credential: Credential,
+    settings: &ConnectionSettings,
 ) -> Result<(CallCredChannel, Arc<CallCredentials>)> {
-    let mut builder = Channel::builder(address.into_uri());
+    let mut builder = configure_endpoint(address, settings);
     if credential.is_tls_enabled() {
         builder = builder.tls_config(credential.tls_config().clone().unwrap())?;
     }
@@ -132,6 +144,17 @@ impl CredentialInjector {
 
 impl Interceptor for CredentialInjector {
     fn call(&mut self, request: Request<()>) -> StdResult<Request<()>, Status> {
-        Ok(self.call_credentials.inject(request))
+        Ok(with_trace_context(self.call_credentials.inject(request)))
     }
 }
+
+#[cfg(feature = "tracing")]
+fn with_trace_context(mut request: Request<()>) -> Request<()> {
+    inject_context(request.metadata_mut());
+    request
+}
+
+#[cfg(not(feature = "tracing"))]
+fn with_trace_context(request: Request<()>) -> Request<()> {
+    request
+}
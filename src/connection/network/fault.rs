@@ -0,0 +1,139 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{
+    collections::VecDeque,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures::{
+    future::{self, BoxFuture},
+    stream::{self, BoxStream},
+    FutureExt, StreamExt,
+};
+use tokio::time::{sleep, timeout};
+use tonic::{body::BoxBody, client::GrpcService, codegen::Service, transport::Error as TonicError, Status, Streaming};
+use typedb_protocol::transaction;
+
+use super::channel::GRPCChannel;
+use crate::{
+    common::{address::Address, StdResult},
+    testing::{Fault, FaultPolicy},
+};
+
+const REORDER_WINDOW: Duration = Duration::from_millis(10);
+
+#[derive(Clone, Debug)]
+pub(super) struct FaultInjectingChannel<Channel: GRPCChannel> {
+    inner: Channel,
+    address: Address,
+    policy: FaultPolicy,
+}
+
+impl<Channel: GRPCChannel> FaultInjectingChannel<Channel> {
+    pub(super) fn new(inner: Channel, address: Address, policy: FaultPolicy) -> Self {
+        Self { inner, address, policy }
+    }
+}
+
+impl<Channel: GRPCChannel> Service<http::Request<BoxBody>> for FaultInjectingChannel<Channel>
+where
+    Channel::Future: Send,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = TonicError;
+    type Future = BoxFuture<'static, StdResult<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<StdResult<(), Self::Error>> {
+        GrpcService::poll_ready(&mut self.inner, cx)
+    }
+
+    fn call(&mut self, request: http::Request<BoxBody>) -> Self::Future {
+        let response = match self.policy.take_fault(&self.address) {
+            Some(fault) => future::ready(Ok(fault_status(fault).to_http())).boxed(),
+            None => GrpcService::call(&mut self.inner, request).boxed(),
+        };
+        match self.policy.latency() {
+            Some(latency) => async move {
+                sleep(latency).await;
+                response.await
+            }
+            .boxed(),
+            None => response,
+        }
+    }
+}
+
+impl<Channel: GRPCChannel> GRPCChannel for FaultInjectingChannel<Channel>
+where
+    Channel::Future: Send,
+{
+    fn is_plaintext(&self) -> bool {
+        self.inner.is_plaintext()
+    }
+}
+
+fn fault_status(fault: Fault) -> Status {
+    match fault {
+        Fault::Unavailable => Status::unavailable("Injected fault: the server is unavailable."),
+        Fault::RstStream => Status::unknown("Injected fault: Received Rst Stream"),
+    }
+}
+
+pub(super) fn inject_transaction_faults(
+    source: Streaming<transaction::Server>,
+    policy: Option<FaultPolicy>,
+) -> BoxStream<'static, StdResult<transaction::Server, Status>> {
+    let (drop_after, reorder) = match policy {
+        Some(policy) => policy.transaction_stream_faults(),
+        None => return source.boxed(),
+    };
+
+    let source = stream::unfold((Some(source), 0), move |(source, received)| async move {
+        let mut source = source?;
+        if drop_after.map_or(false, |limit| received >= limit) {
+            return Some((Err(fault_status(Fault::RstStream)), (None, received)));
+        }
+        let message = source.next().await?;
+        Some((message, (Some(source), received + 1)))
+    })
+    .fuse()
+    .boxed();
+    if !reorder {
+        return source;
+    }
+
+    stream::unfold((source, VecDeque::new()), |(mut source, mut pending)| async move {
+        if let Some(message) = pending.pop_front() {
+            return Some((message, (source, pending)));
+        }
+        let first = source.next().await?;
+        match timeout(REORDER_WINDOW, source.next()).await {
+            Ok(Some(second)) => {
+                pending.push_back(first);
+                Some((second, (source, pending)))
+            }
+            Ok(None) | Err(_) => Some((first, (source, pending))),
+        }
+    })
+    .boxed()
+}
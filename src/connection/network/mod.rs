@@ -20,6 +20,8 @@
  */
 
 mod channel;
+#[cfg(feature = "testing")]
+mod fault;
 mod proto;
 mod stub;
 pub(super) mod transmitter;
@@ -22,20 +22,22 @@
 use std::collections::HashMap;
 
 use chrono::NaiveDateTime;
+use itertools::Itertools;
 use typedb_protocol::{
     attribute::value::Value as ValueProto, attribute_type::ValueType, concept as concept_proto, numeric::Value,
-    r#type::Encoding, Concept as ConceptProto, ConceptMap as ConceptMapProto, Numeric as NumericProto,
-    Thing as ThingProto, Type as TypeProto,
+    r#type::Encoding, Concept as ConceptProto, ConceptMap as ConceptMapProto, ConceptMapGroup as ConceptMapGroupProto,
+    Exception as ExceptionProto, Explainable as ExplainableProto, Explainables as ExplainablesProto,
+    Numeric as NumericProto, NumericGroup as NumericGroupProto, Thing as ThingProto, Type as TypeProto,
 };
 
-use super::TryFromProto;
+use super::{IntoProto, TryFromProto};
 use crate::{
-    answer::{ConceptMap, Numeric},
+    answer::{ConceptMap, ConceptMapGroup, Explainable, Explainables, Numeric, NumericGroup},
     concept::{
-        Attribute, AttributeType, BooleanAttribute, BooleanAttributeType, Concept, DateTimeAttribute,
+        attribute, Attribute, AttributeType, BooleanAttribute, BooleanAttributeType, Concept, DateTimeAttribute,
         DateTimeAttributeType, DoubleAttribute, DoubleAttributeType, Entity, EntityType, LongAttribute,
-        LongAttributeType, Relation, RelationType, RoleType, RootAttributeType, RootThingType, ScopedLabel,
-        StringAttribute, StringAttributeType, Thing, ThingType, Type,
+        LongAttributeType, Relation, RelationType, RoleType, RootAttributeType, RootThingType, SchemaException,
+        ScopedLabel, StringAttribute, StringAttributeType, Thing, ThingType, Type,
     },
     connection::network::proto::FromProto,
     error::{ConnectionError, InternalError},
@@ -53,13 +55,60 @@ impl TryFromProto<NumericProto> for Numeric {
     }
 }
 
+impl TryFromProto<NumericGroupProto> for NumericGroup {
+    fn try_from_proto(proto: NumericGroupProto) -> Result<Self> {
+        Ok(Self {
+            owner: Concept::try_from_proto(proto.owner.ok_or(ConnectionError::MissingResponseField("owner"))?)?,
+            numeric: Numeric::try_from_proto(proto.number.ok_or(ConnectionError::MissingResponseField("number"))?)?,
+        })
+    }
+}
+
 impl TryFromProto<ConceptMapProto> for ConceptMap {
     fn try_from_proto(proto: ConceptMapProto) -> Result<Self> {
         let mut map = HashMap::with_capacity(proto.map.len());
         for (k, v) in proto.map {
             map.insert(k, Concept::try_from_proto(v)?);
         }
-        Ok(Self { map })
+        let explainables = proto.explainables.map(Explainables::from_proto).unwrap_or_default();
+        Ok(Self { map, explainables })
+    }
+}
+
+impl FromProto<ExplainablesProto> for Explainables {
+    fn from_proto(proto: ExplainablesProto) -> Self {
+        let relations =
+            proto.relations.into_iter().map(|(var, explainable)| (var, Explainable::from_proto(explainable))).collect();
+        let attributes = proto
+            .attributes
+            .into_iter()
+            .map(|(var, explainable)| (var, Explainable::from_proto(explainable)))
+            .collect();
+        let ownerships = proto
+            .ownerships
+            .into_iter()
+            .flat_map(|(owner, owned)| {
+                owned.owned.into_iter().map(move |(attribute, explainable)| {
+                    ((owner.clone(), attribute), Explainable::from_proto(explainable))
+                })
+            })
+            .collect();
+        Self::new(relations, attributes, ownerships)
+    }
+}
+
+impl FromProto<ExplainableProto> for Explainable {
+    fn from_proto(proto: ExplainableProto) -> Self {
+        Self::new(proto.conjunction, proto.id)
+    }
+}
+
+impl TryFromProto<ConceptMapGroupProto> for ConceptMapGroup {
+    fn try_from_proto(proto: ConceptMapGroupProto) -> Result<Self> {
+        Ok(Self {
+            owner: Concept::try_from_proto(proto.owner.ok_or(ConnectionError::MissingResponseField("owner"))?)?,
+            concept_maps: proto.concept_maps.into_iter().map(ConceptMap::try_from_proto).try_collect()?,
+        })
     }
 }
 
@@ -82,11 +131,37 @@ impl TryFromProto<i32> for Encoding {
 impl TryFromProto<TypeProto> for Type {
     fn try_from_proto(proto: TypeProto) -> Result<Self> {
         match Encoding::try_from_proto(proto.encoding)? {
-            Encoding::ThingType => Ok(Self::Thing(ThingType::Root(RootThingType::default()))),
-            Encoding::EntityType => Ok(Self::Thing(ThingType::Entity(EntityType::from_proto(proto)))),
-            Encoding::RelationType => Ok(Self::Thing(ThingType::Relation(RelationType::from_proto(proto)))),
-            Encoding::AttributeType => Ok(Self::Thing(ThingType::Attribute(AttributeType::try_from_proto(proto)?))),
             Encoding::RoleType => Ok(Self::Role(RoleType::from_proto(proto))),
+            _ => Ok(Self::Thing(ThingType::try_from_proto(proto)?)),
+        }
+    }
+}
+
+impl TryFromProto<TypeProto> for ThingType {
+    fn try_from_proto(proto: TypeProto) -> Result<Self> {
+        match Encoding::try_from_proto(proto.encoding)? {
+            Encoding::ThingType => Ok(Self::Root(RootThingType::default())),
+            Encoding::EntityType => Ok(Self::Entity(EntityType::from_proto(proto))),
+            Encoding::RelationType => Ok(Self::Relation(RelationType::from_proto(proto))),
+            Encoding::AttributeType => Ok(Self::Attribute(AttributeType::try_from_proto(proto)?)),
+            Encoding::RoleType => Err(InternalError::UnexpectedConceptEncoding(proto.encoding, "ThingType").into()),
+        }
+    }
+}
+
+impl IntoProto<TypeProto> for ThingType {
+    fn into_proto(self) -> TypeProto {
+        match self {
+            Self::Root(root_thing_type) => {
+                TypeProto { label: root_thing_type.label, encoding: Encoding::ThingType.into(), ..Default::default() }
+            }
+            Self::Entity(entity_type) => {
+                TypeProto { label: entity_type.label, encoding: Encoding::EntityType.into(), ..Default::default() }
+            }
+            Self::Relation(relation_type) => {
+                TypeProto { label: relation_type.label, encoding: Encoding::RelationType.into(), ..Default::default() }
+            }
+            Self::Attribute(attribute_type) => attribute_type.into_proto(),
         }
     }
 }
@@ -109,6 +184,19 @@ impl TryFromProto<i32> for ValueType {
     }
 }
 
+impl IntoProto<ValueType> for attribute::ValueType {
+    fn into_proto(self) -> ValueType {
+        match self {
+            Self::Object => ValueType::Object,
+            Self::Boolean => ValueType::Boolean,
+            Self::Long => ValueType::Long,
+            Self::Double => ValueType::Double,
+            Self::String => ValueType::String,
+            Self::DateTime => ValueType::Datetime,
+        }
+    }
+}
+
 impl TryFromProto<TypeProto> for AttributeType {
     fn try_from_proto(proto: TypeProto) -> Result<Self> {
         match ValueType::try_from_proto(proto.value_type)? {
@@ -122,12 +210,35 @@ impl TryFromProto<TypeProto> for AttributeType {
     }
 }
 
+impl IntoProto<TypeProto> for AttributeType {
+    fn into_proto(self) -> TypeProto {
+        let value_type = self.value_type().into_proto();
+        TypeProto {
+            label: self.label().to_owned(),
+            encoding: Encoding::AttributeType.into(),
+            value_type: value_type.into(),
+            ..Default::default()
+        }
+    }
+}
+
 impl FromProto<TypeProto> for RoleType {
     fn from_proto(proto: TypeProto) -> Self {
         Self::new(ScopedLabel::new(proto.scope, proto.label))
     }
 }
 
+impl IntoProto<TypeProto> for RoleType {
+    fn into_proto(self) -> TypeProto {
+        TypeProto {
+            label: self.label.name,
+            scope: self.label.scope,
+            encoding: Encoding::RoleType.into(),
+            ..Default::default()
+        }
+    }
+}
+
 impl TryFromProto<ThingProto> for Thing {
     fn try_from_proto(proto: ThingProto) -> Result<Self> {
         let encoding = proto.r#type.clone().ok_or(ConnectionError::MissingResponseField("type"))?.encoding;
@@ -135,11 +246,23 @@ impl TryFromProto<ThingProto> for Thing {
             Encoding::EntityType => Ok(Self::Entity(Entity::try_from_proto(proto)?)),
             Encoding::RelationType => Ok(Self::Relation(Relation::try_from_proto(proto)?)),
             Encoding::AttributeType => Ok(Self::Attribute(Attribute::try_from_proto(proto)?)),
-            _ => todo!(),
+            _ => Err(InternalError::UnexpectedConceptEncoding(encoding, "Thing").into()),
         }
     }
 }
 
+impl IntoProto<ThingProto> for Thing {
+    fn into_proto(self) -> ThingProto {
+        ThingProto { iid: self.iid().to_vec(), ..Default::default() }
+    }
+}
+
+impl IntoProto<ThingProto> for Attribute {
+    fn into_proto(self) -> ThingProto {
+        ThingProto { iid: self.iid().to_vec(), ..Default::default() }
+    }
+}
+
 impl TryFromProto<ThingProto> for Entity {
     fn try_from_proto(proto: ThingProto) -> Result<Self> {
         Ok(Self {
@@ -194,3 +317,9 @@ impl TryFromProto<ThingProto> for Attribute {
         }
     }
 }
+
+impl FromProto<ExceptionProto> for SchemaException {
+    fn from_proto(proto: ExceptionProto) -> Self {
+        Self { code: proto.code, message: proto.message }
+    }
+}
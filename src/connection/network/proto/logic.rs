@@ -0,0 +1,69 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use typedb_protocol::{Explanation as ExplanationProto, Rule as RuleProto};
+use typeql_lang::{
+    parse_pattern, parse_variable,
+    pattern::{Pattern, Variable},
+};
+
+use super::TryFromProto;
+use crate::{
+    answer::ConceptMap,
+    common::{
+        error::{ConnectionError, InternalError},
+        Result,
+    },
+    logic::{Explanation, Rule},
+};
+
+impl TryFromProto<RuleProto> for Rule {
+    fn try_from_proto(proto: RuleProto) -> Result<Self> {
+        let RuleProto { label, when, then } = proto;
+        let when =
+            match parse_pattern(&when).map_err(|err| InternalError::RuleParseFailed(when.clone(), err.to_string()))? {
+                Pattern::Conjunction(conjunction) => conjunction,
+                _ => return Err(InternalError::UnexpectedRulePattern(when).into()),
+            };
+        let then =
+            match parse_variable(&then).map_err(|err| InternalError::RuleParseFailed(then.clone(), err.to_string()))? {
+                Variable::Thing(thing) => thing,
+                _ => return Err(InternalError::UnexpectedRulePattern(then).into()),
+            };
+        Ok(Self::new(label, when, then))
+    }
+}
+
+impl TryFromProto<ExplanationProto> for Explanation {
+    fn try_from_proto(proto: ExplanationProto) -> Result<Self> {
+        let ExplanationProto { rule, var_mapping, condition, conclusion } = proto;
+        Ok(Self {
+            rule: Rule::try_from_proto(rule.ok_or(ConnectionError::MissingResponseField("rule"))?)?,
+            conclusion: ConceptMap::try_from_proto(
+                conclusion.ok_or(ConnectionError::MissingResponseField("conclusion"))?,
+            )?,
+            condition: ConceptMap::try_from_proto(
+                condition.ok_or(ConnectionError::MissingResponseField("condition"))?,
+            )?,
+            variable_mapping: var_mapping.into_iter().map(|(var, list)| (var, list.vars)).collect(),
+        })
+    }
+}
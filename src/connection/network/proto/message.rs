@@ -23,18 +23,31 @@ use std::time::Duration;
 
 use itertools::Itertools;
 use typedb_protocol::{
-    cluster_database_manager, core_database, core_database_manager, query_manager, server_manager, session, transaction,
+    attribute as attribute_proto, cluster_database_manager, cluster_user, cluster_user_manager, concept_manager,
+    core_database, core_database_manager, logic_manager, query_manager, r#type, relation, rule, server_manager,
+    session, thing, thing_type as thing_type_proto, transaction,
 };
 
 use super::{FromProto, IntoProto, TryFromProto};
 use crate::{
-    answer::{ConceptMap, Numeric},
-    common::{info::DatabaseInfo, RequestID, Result},
+    answer::{ConceptMap, ConceptMapGroup, Numeric, NumericGroup},
+    common::{
+        info::{DatabaseInfo, UserInfo},
+        RequestID, Result,
+    },
+    concept::{
+        Attribute, AttributeType, EntityType, Relation, RelationType, RoleType, SchemaException, Thing, ThingType,
+    },
     connection::{
-        message::{QueryRequest, QueryResponse, Request, Response, TransactionRequest, TransactionResponse},
+        message::{
+            ConceptRequest, ConceptResponse, LogicRequest, LogicResponse, QueryRequest, QueryResponse, Request,
+            Response, RuleRequest, RuleResponse, ThingRequest, ThingResponse, ThingTypeRequest, ThingTypeResponse,
+            TransactionRequest, TransactionResponse,
+        },
         network::proto::TryIntoProto,
     },
     error::{ConnectionError, InternalError},
+    logic::{Explanation, Rule},
 };
 
 impl TryIntoProto<server_manager::all::Req> for Request {
@@ -166,6 +179,73 @@ impl TryIntoProto<transaction::Client> for Request {
     }
 }
 
+impl TryIntoProto<cluster_user_manager::contains::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user_manager::contains::Req> {
+        match self {
+            Request::UsersContains { username } => Ok(cluster_user_manager::contains::Req { username }),
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
+impl TryIntoProto<cluster_user_manager::create::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user_manager::create::Req> {
+        match self {
+            Request::UsersCreate { username, password } => Ok(cluster_user_manager::create::Req { username, password }),
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
+impl TryIntoProto<cluster_user_manager::delete::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user_manager::delete::Req> {
+        match self {
+            Request::UsersDelete { username } => Ok(cluster_user_manager::delete::Req { username }),
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
+impl TryIntoProto<cluster_user_manager::all::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user_manager::all::Req> {
+        match self {
+            Request::UsersAll => Ok(cluster_user_manager::all::Req {}),
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
+impl TryIntoProto<cluster_user_manager::get::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user_manager::get::Req> {
+        match self {
+            Request::UsersGet { username } => Ok(cluster_user_manager::get::Req { username }),
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
+impl TryIntoProto<cluster_user_manager::password_set::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user_manager::password_set::Req> {
+        match self {
+            Request::UsersPasswordSet { username, password } => {
+                Ok(cluster_user_manager::password_set::Req { username, password })
+            }
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
+impl TryIntoProto<cluster_user::password_update::Req> for Request {
+    fn try_into_proto(self) -> Result<cluster_user::password_update::Req> {
+        match self {
+            Request::UserPasswordUpdate { username, password_old, password_new } => {
+                Ok(cluster_user::password_update::Req { username, password_old, password_new })
+            }
+            other => Err(InternalError::UnexpectedRequestType(format!("{other:?}")).into()),
+        }
+    }
+}
+
 impl TryFromProto<server_manager::all::Res> for Response {
     fn try_from_proto(proto: server_manager::all::Res) -> Result<Self> {
         let servers = proto.servers.into_iter().map(|server| server.address.parse()).try_collect()?;
@@ -237,8 +317,8 @@ impl FromProto<session::open::Res> for Response {
 }
 
 impl FromProto<session::pulse::Res> for Response {
-    fn from_proto(_proto: session::pulse::Res) -> Self {
-        Self::SessionPulse
+    fn from_proto(proto: session::pulse::Res) -> Self {
+        Self::SessionPulse { is_alive: proto.alive }
     }
 }
 
@@ -248,6 +328,48 @@ impl FromProto<session::close::Res> for Response {
     }
 }
 
+impl FromProto<cluster_user_manager::contains::Res> for Response {
+    fn from_proto(proto: cluster_user_manager::contains::Res) -> Self {
+        Self::UsersContains { contains: proto.contains }
+    }
+}
+
+impl FromProto<cluster_user_manager::create::Res> for Response {
+    fn from_proto(_proto: cluster_user_manager::create::Res) -> Self {
+        Self::UsersCreate
+    }
+}
+
+impl FromProto<cluster_user_manager::delete::Res> for Response {
+    fn from_proto(_proto: cluster_user_manager::delete::Res) -> Self {
+        Self::UsersDelete
+    }
+}
+
+impl FromProto<cluster_user_manager::all::Res> for Response {
+    fn from_proto(proto: cluster_user_manager::all::Res) -> Self {
+        Self::UsersAll { users: proto.users.into_iter().map(UserInfo::from_proto).collect() }
+    }
+}
+
+impl FromProto<cluster_user_manager::get::Res> for Response {
+    fn from_proto(proto: cluster_user_manager::get::Res) -> Self {
+        Self::UsersGet { user: proto.user.map(UserInfo::from_proto) }
+    }
+}
+
+impl FromProto<cluster_user_manager::password_set::Res> for Response {
+    fn from_proto(_proto: cluster_user_manager::password_set::Res) -> Self {
+        Self::UsersPasswordSet
+    }
+}
+
+impl FromProto<cluster_user::password_update::Res> for Response {
+    fn from_proto(_proto: cluster_user::password_update::Res) -> Self {
+        Self::UserPasswordUpdate
+    }
+}
+
 impl IntoProto<transaction::Req> for TransactionRequest {
     fn into_proto(self) -> transaction::Req {
         let mut request_id = None;
@@ -266,6 +388,17 @@ impl IntoProto<transaction::Req> for TransactionRequest {
             TransactionRequest::Query(query_request) => {
                 transaction::req::Req::QueryManagerReq(query_request.into_proto())
             }
+            TransactionRequest::Concept(concept_request) => {
+                transaction::req::Req::ConceptManagerReq(concept_request.into_proto())
+            }
+            TransactionRequest::ThingType(thing_type_request) => {
+                transaction::req::Req::TypeReq(thing_type_request.into_proto())
+            }
+            TransactionRequest::Thing(thing_request) => transaction::req::Req::ThingReq(thing_request.into_proto()),
+            TransactionRequest::Logic(logic_request) => {
+                transaction::req::Req::LogicManagerReq(logic_request.into_proto())
+            }
+            TransactionRequest::Rule(rule_request) => transaction::req::Req::RuleReq(rule_request.into_proto()),
             TransactionRequest::Stream { request_id: req_id } => {
                 request_id = Some(req_id);
                 transaction::req::Req::StreamReq(transaction::stream::Req {})
@@ -289,7 +422,21 @@ impl TryFromProto<transaction::Res> for TransactionResponse {
             Some(transaction::res::Res::QueryManagerRes(res)) => {
                 Ok(TransactionResponse::Query(QueryResponse::try_from_proto(res)?))
             }
-            Some(_) => todo!(),
+            Some(transaction::res::Res::ConceptManagerRes(res)) => {
+                Ok(TransactionResponse::Concept(ConceptResponse::try_from_proto(res)?))
+            }
+            Some(transaction::res::Res::TypeRes(res)) => {
+                Ok(TransactionResponse::ThingType(ThingTypeResponse::try_from_proto(res)?))
+            }
+            Some(transaction::res::Res::ThingRes(res)) => {
+                Ok(TransactionResponse::Thing(ThingResponse::try_from_proto(res)?))
+            }
+            Some(transaction::res::Res::LogicManagerRes(res)) => {
+                Ok(TransactionResponse::Logic(LogicResponse::try_from_proto(res)?))
+            }
+            Some(transaction::res::Res::RuleRes(res)) => {
+                Ok(TransactionResponse::Rule(RuleResponse::try_from_proto(res)?))
+            }
             None => Err(ConnectionError::MissingResponseField("res").into()),
         }
     }
@@ -301,7 +448,19 @@ impl TryFromProto<transaction::ResPart> for TransactionResponse {
             Some(transaction::res_part::Res::QueryManagerResPart(res_part)) => {
                 Ok(TransactionResponse::Query(QueryResponse::try_from_proto(res_part)?))
             }
-            Some(_) => todo!(),
+            Some(transaction::res_part::Res::ConceptManagerResPart(res_part)) => {
+                Ok(TransactionResponse::Concept(ConceptResponse::try_from_proto(res_part)?))
+            }
+            Some(transaction::res_part::Res::TypeResPart(res_part)) => {
+                Ok(TransactionResponse::ThingType(ThingTypeResponse::try_from_proto(res_part)?))
+            }
+            Some(transaction::res_part::Res::ThingResPart(res_part)) => {
+                Ok(TransactionResponse::Thing(ThingResponse::try_from_proto(res_part)?))
+            }
+            Some(transaction::res_part::Res::LogicManagerResPart(res_part)) => {
+                Ok(TransactionResponse::Logic(LogicResponse::try_from_proto(res_part)?))
+            }
+            Some(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
             None => Err(ConnectionError::MissingResponseField("res").into()),
         }
     }
@@ -334,7 +493,17 @@ impl IntoProto<query_manager::Req> for QueryRequest {
                 (query_manager::req::Req::MatchAggregateReq(query_manager::match_aggregate::Req { query }), options)
             }
 
-            _ => todo!(),
+            QueryRequest::MatchGroup { query, options } => {
+                (query_manager::req::Req::MatchGroupReq(query_manager::match_group::Req { query }), options)
+            }
+            QueryRequest::MatchGroupAggregate { query, options } => (
+                query_manager::req::Req::MatchGroupAggregateReq(query_manager::match_group_aggregate::Req { query }),
+                options,
+            ),
+
+            QueryRequest::Explain { explainable_id, options } => {
+                (query_manager::req::Req::ExplainReq(query_manager::explain::Req { explainable_id }), options)
+            }
         };
         query_manager::Req { req: Some(req), options: Some(options.into_proto()) }
     }
@@ -366,7 +535,346 @@ impl TryFromProto<query_manager::ResPart> for QueryResponse {
             Some(query_manager::res_part::Res::UpdateResPart(res)) => Ok(QueryResponse::Update {
                 answers: res.answers.into_iter().map(ConceptMap::try_from_proto).try_collect()?,
             }),
-            Some(_) => todo!(),
+            Some(query_manager::res_part::Res::MatchGroupResPart(res)) => Ok(QueryResponse::MatchGroup {
+                answers: res.answers.into_iter().map(ConceptMapGroup::try_from_proto).try_collect()?,
+            }),
+            Some(query_manager::res_part::Res::MatchGroupAggregateResPart(res)) => {
+                Ok(QueryResponse::MatchGroupAggregate {
+                    answers: res.answers.into_iter().map(NumericGroup::try_from_proto).try_collect()?,
+                })
+            }
+            Some(query_manager::res_part::Res::ExplainResPart(res)) => Ok(QueryResponse::Explain {
+                answers: res.explanations.into_iter().map(Explanation::try_from_proto).try_collect()?,
+            }),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl IntoProto<concept_manager::Req> for ConceptRequest {
+    fn into_proto(self) -> concept_manager::Req {
+        let req = match self {
+            Self::GetThingType { label } => {
+                concept_manager::req::Req::GetThingTypeReq(concept_manager::get_thing_type::Req { label })
+            }
+            Self::GetThing { iid } => concept_manager::req::Req::GetThingReq(concept_manager::get_thing::Req { iid }),
+
+            Self::PutEntityType { label } => {
+                concept_manager::req::Req::PutEntityTypeReq(concept_manager::put_entity_type::Req { label })
+            }
+            Self::PutRelationType { label } => {
+                concept_manager::req::Req::PutRelationTypeReq(concept_manager::put_relation_type::Req { label })
+            }
+            Self::PutAttributeType { label, value_type } => {
+                concept_manager::req::Req::PutAttributeTypeReq(concept_manager::put_attribute_type::Req {
+                    label,
+                    value_type: value_type.into_proto().into(),
+                })
+            }
+
+            Self::GetSchemaExceptions => {
+                concept_manager::req::Req::GetSchemaExceptionsReq(concept_manager::get_schema_exceptions::Req {})
+            }
+        };
+        concept_manager::Req { req: Some(req) }
+    }
+}
+
+impl TryFromProto<concept_manager::Res> for ConceptResponse {
+    fn try_from_proto(proto: concept_manager::Res) -> Result<Self> {
+        match proto.res {
+            Some(concept_manager::res::Res::GetThingTypeRes(concept_manager::get_thing_type::Res {
+                res: Some(concept_manager::get_thing_type::res::Res::ThingType(thing_type)),
+            })) => Ok(Self::GetThingType { thing_type: Some(ThingType::try_from_proto(thing_type)?) }),
+            Some(concept_manager::res::Res::GetThingTypeRes(_)) => Ok(Self::GetThingType { thing_type: None }),
+            Some(concept_manager::res::Res::GetThingRes(concept_manager::get_thing::Res {
+                res: Some(concept_manager::get_thing::res::Res::Thing(thing)),
+            })) => Ok(Self::GetThing { thing: Some(Thing::try_from_proto(thing)?) }),
+            Some(concept_manager::res::Res::GetThingRes(_)) => Ok(Self::GetThing { thing: None }),
+
+            Some(concept_manager::res::Res::PutEntityTypeRes(res)) => Ok(Self::PutEntityType {
+                entity_type: EntityType::from_proto(
+                    res.entity_type.ok_or(ConnectionError::MissingResponseField("entity_type"))?,
+                ),
+            }),
+            Some(concept_manager::res::Res::PutRelationTypeRes(res)) => Ok(Self::PutRelationType {
+                relation_type: RelationType::from_proto(
+                    res.relation_type.ok_or(ConnectionError::MissingResponseField("relation_type"))?,
+                ),
+            }),
+            Some(concept_manager::res::Res::PutAttributeTypeRes(res)) => Ok(Self::PutAttributeType {
+                attribute_type: AttributeType::try_from_proto(
+                    res.attribute_type.ok_or(ConnectionError::MissingResponseField("attribute_type"))?,
+                )?,
+            }),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl TryFromProto<concept_manager::ResPart> for ConceptResponse {
+    fn try_from_proto(proto: concept_manager::ResPart) -> Result<Self> {
+        match proto.res {
+            Some(concept_manager::res_part::Res::GetSchemaExceptionsResPart(res)) => Ok(Self::GetSchemaExceptions {
+                exceptions: res.exceptions.into_iter().map(SchemaException::from_proto).collect(),
+            }),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl IntoProto<r#type::Req> for ThingTypeRequest {
+    fn into_proto(self) -> r#type::Req {
+        let (thing_type, req) = match self {
+            Self::Delete { thing_type } => (thing_type, r#type::req::Req::TypeDeleteReq(r#type::delete::Req {})),
+            Self::SetLabel { thing_type, new_label } => {
+                (thing_type, r#type::req::Req::TypeSetLabelReq(r#type::set_label::Req { label: new_label }))
+            }
+            Self::SetAbstract { thing_type } => {
+                (thing_type, r#type::req::Req::ThingTypeSetAbstractReq(thing_type_proto::set_abstract::Req {}))
+            }
+
+            Self::GetSupertype { thing_type } => {
+                (thing_type, r#type::req::Req::TypeGetSupertypeReq(r#type::get_supertype::Req {}))
+            }
+            Self::GetSupertypes { thing_type } => {
+                (thing_type, r#type::req::Req::TypeGetSupertypesReq(r#type::get_supertypes::Req {}))
+            }
+            Self::GetSubtypes { thing_type } => {
+                (thing_type, r#type::req::Req::TypeGetSubtypesReq(r#type::get_subtypes::Req {}))
+            }
+
+            Self::GetInstances { thing_type } => {
+                (thing_type, r#type::req::Req::ThingTypeGetInstancesReq(thing_type_proto::get_instances::Req {}))
+            }
+            Self::GetOwns { thing_type, value_type, keys_only } => (
+                thing_type,
+                r#type::req::Req::ThingTypeGetOwnsReq(thing_type_proto::get_owns::Req {
+                    filter: value_type.map(|value_type| {
+                        thing_type_proto::get_owns::req::Filter::ValueType(value_type.into_proto().into())
+                    }),
+                    keys_only,
+                }),
+            ),
+            Self::GetPlays { thing_type } => {
+                (thing_type, r#type::req::Req::ThingTypeGetPlaysReq(thing_type_proto::get_plays::Req {}))
+            }
+        };
+        r#type::Req { label: thing_type.label().to_owned(), scope: String::new(), req: Some(req) }
+    }
+}
+
+impl TryFromProto<r#type::Res> for ThingTypeResponse {
+    fn try_from_proto(proto: r#type::Res) -> Result<Self> {
+        match proto.res {
+            Some(r#type::res::Res::TypeDeleteRes(_)) => Ok(Self::Delete),
+            Some(r#type::res::Res::TypeSetLabelRes(_)) => Ok(Self::SetLabel),
+            Some(r#type::res::Res::ThingTypeSetAbstractRes(_)) => Ok(Self::SetAbstract),
+            Some(r#type::res::Res::TypeGetSupertypeRes(res)) => Ok(Self::GetSupertype {
+                supertype: match res.res {
+                    Some(r#type::get_supertype::res::Res::Type(supertype)) => {
+                        Some(ThingType::try_from_proto(supertype)?)
+                    }
+                    None => None,
+                },
+            }),
+            Some(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl TryFromProto<r#type::ResPart> for ThingTypeResponse {
+    fn try_from_proto(proto: r#type::ResPart) -> Result<Self> {
+        match proto.res {
+            Some(r#type::res_part::Res::TypeGetSupertypesResPart(res)) => Ok(Self::GetSupertypes {
+                supertypes: res.types.into_iter().map(ThingType::try_from_proto).try_collect()?,
+            }),
+            Some(r#type::res_part::Res::TypeGetSubtypesResPart(res)) => {
+                Ok(Self::GetSubtypes { subtypes: res.types.into_iter().map(ThingType::try_from_proto).try_collect()? })
+            }
+            Some(r#type::res_part::Res::ThingTypeGetInstancesResPart(res)) => {
+                Ok(Self::GetInstances { instances: res.things.into_iter().map(Thing::try_from_proto).try_collect()? })
+            }
+            Some(r#type::res_part::Res::ThingTypeGetOwnsResPart(res)) => Ok(Self::GetOwns {
+                attribute_types: res.attribute_types.into_iter().map(AttributeType::try_from_proto).try_collect()?,
+            }),
+            Some(r#type::res_part::Res::ThingTypeGetPlaysResPart(res)) => {
+                Ok(Self::GetPlays { role_types: res.role_types.into_iter().map(RoleType::from_proto).collect() })
+            }
+            Some(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl IntoProto<thing::Req> for ThingRequest {
+    fn into_proto(self) -> thing::Req {
+        let (iid, req) = match self {
+            Self::Delete { iid } => (iid, thing::req::Req::ThingDeleteReq(thing::delete::Req {})),
+
+            Self::GetHas { iid, attribute_types, keys_only } => (
+                iid,
+                thing::req::Req::ThingGetHasReq(thing::get_has::Req {
+                    attribute_types: attribute_types.into_iter().map(IntoProto::into_proto).collect(),
+                    keys_only,
+                }),
+            ),
+            Self::SetHas { iid, attribute } => {
+                (iid, thing::req::Req::ThingSetHasReq(thing::set_has::Req { attribute: Some(attribute.into_proto()) }))
+            }
+            Self::UnsetHas { iid, attribute } => (
+                iid,
+                thing::req::Req::ThingUnsetHasReq(thing::unset_has::Req { attribute: Some(attribute.into_proto()) }),
+            ),
+
+            Self::GetRelations { iid, role_types } => (
+                iid,
+                thing::req::Req::ThingGetRelationsReq(thing::get_relations::Req {
+                    role_types: role_types.into_iter().map(IntoProto::into_proto).collect(),
+                }),
+            ),
+            Self::GetPlaying { iid } => (iid, thing::req::Req::ThingGetPlayingReq(thing::get_playing::Req {})),
+
+            Self::RelationGetPlayersByRoleType { iid } => {
+                (iid, thing::req::Req::RelationGetPlayersByRoleTypeReq(relation::get_players_by_role_type::Req {}))
+            }
+            Self::RelationAddPlayer { iid, role_type, player } => (
+                iid,
+                thing::req::Req::RelationAddPlayerReq(relation::add_player::Req {
+                    role_type: Some(role_type.into_proto()),
+                    player: Some(player.into_proto()),
+                }),
+            ),
+            Self::RelationRemovePlayer { iid, role_type, player } => (
+                iid,
+                thing::req::Req::RelationRemovePlayerReq(relation::remove_player::Req {
+                    role_type: Some(role_type.into_proto()),
+                    player: Some(player.into_proto()),
+                }),
+            ),
+
+            Self::AttributeGetOwners { iid, thing_type } => (
+                iid,
+                thing::req::Req::AttributeGetOwnersReq(attribute_proto::get_owners::Req {
+                    filter: thing_type
+                        .map(|thing_type| attribute_proto::get_owners::req::Filter::ThingType(thing_type.into_proto())),
+                }),
+            ),
+        };
+        thing::Req { iid, req: Some(req) }
+    }
+}
+
+impl TryFromProto<thing::Res> for ThingResponse {
+    fn try_from_proto(proto: thing::Res) -> Result<Self> {
+        match proto.res {
+            Some(thing::res::Res::ThingDeleteRes(_)) => Ok(Self::Delete),
+            Some(thing::res::Res::ThingSetHasRes(_)) => Ok(Self::SetHas),
+            Some(thing::res::Res::ThingUnsetHasRes(_)) => Ok(Self::UnsetHas),
+            Some(thing::res::Res::RelationAddPlayerRes(_)) => Ok(Self::RelationAddPlayer),
+            Some(thing::res::Res::RelationRemovePlayerRes(_)) => Ok(Self::RelationRemovePlayer),
+            Some(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl TryFromProto<thing::ResPart> for ThingResponse {
+    fn try_from_proto(proto: thing::ResPart) -> Result<Self> {
+        match proto.res {
+            Some(thing::res_part::Res::ThingGetHasResPart(res)) => Ok(Self::GetHas {
+                attributes: res.attributes.into_iter().map(Attribute::try_from_proto).try_collect()?,
+            }),
+            Some(thing::res_part::Res::ThingGetRelationsResPart(res)) => Ok(Self::GetRelations {
+                relations: res.relations.into_iter().map(Relation::try_from_proto).try_collect()?,
+            }),
+            Some(thing::res_part::Res::ThingGetPlayingResPart(res)) => {
+                Ok(Self::GetPlaying { role_types: res.role_types.into_iter().map(RoleType::from_proto).collect() })
+            }
+            Some(thing::res_part::Res::RelationGetPlayersByRoleTypeResPart(res)) => {
+                Ok(Self::RelationGetPlayersByRoleType {
+                    role_types_with_players: res
+                        .role_types_with_players
+                        .into_iter()
+                        .map(|role_type_with_player| -> Result<_> {
+                            let role_type = role_type_with_player
+                                .role_type
+                                .ok_or(ConnectionError::MissingResponseField("role_type"))?;
+                            let player =
+                                role_type_with_player.player.ok_or(ConnectionError::MissingResponseField("player"))?;
+                            Ok((RoleType::from_proto(role_type), Thing::try_from_proto(player)?))
+                        })
+                        .try_collect()?,
+                })
+            }
+            Some(thing::res_part::Res::AttributeGetOwnersResPart(res)) => Ok(Self::AttributeGetOwners {
+                owners: res.things.into_iter().map(Thing::try_from_proto).try_collect()?,
+            }),
+            Some(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl IntoProto<logic_manager::Req> for LogicRequest {
+    fn into_proto(self) -> logic_manager::Req {
+        let req = match self {
+            Self::GetRule { label } => logic_manager::req::Req::GetRuleReq(logic_manager::get_rule::Req { label }),
+            Self::PutRule { label, when, then } => logic_manager::req::Req::PutRuleReq(logic_manager::put_rule::Req {
+                label,
+                when: when.to_string(),
+                then: then.to_string(),
+            }),
+            Self::GetRules => logic_manager::req::Req::GetRulesReq(logic_manager::get_rules::Req {}),
+        };
+        logic_manager::Req { req: Some(req) }
+    }
+}
+
+impl TryFromProto<logic_manager::Res> for LogicResponse {
+    fn try_from_proto(proto: logic_manager::Res) -> Result<Self> {
+        match proto.res {
+            Some(logic_manager::res::Res::GetRuleRes(logic_manager::get_rule::Res {
+                res: Some(logic_manager::get_rule::res::Res::Rule(rule)),
+            })) => Ok(Self::GetRule { rule: Some(Rule::try_from_proto(rule)?) }),
+            Some(logic_manager::res::Res::GetRuleRes(_)) => Ok(Self::GetRule { rule: None }),
+            Some(logic_manager::res::Res::PutRuleRes(res)) => Ok(Self::PutRule {
+                rule: Rule::try_from_proto(res.rule.ok_or(ConnectionError::MissingResponseField("rule"))?)?,
+            }),
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl TryFromProto<logic_manager::ResPart> for LogicResponse {
+    fn try_from_proto(proto: logic_manager::ResPart) -> Result<Self> {
+        match proto.res {
+            Some(logic_manager::res_part::Res::GetRulesResPart(res)) => {
+                Ok(Self::GetRules { rules: res.rules.into_iter().map(Rule::try_from_proto).try_collect()? })
+            }
+            None => Err(ConnectionError::MissingResponseField("res").into()),
+        }
+    }
+}
+
+impl IntoProto<rule::Req> for RuleRequest {
+    fn into_proto(self) -> rule::Req {
+        let (label, req) = match self {
+            Self::Delete { label } => (label, rule::req::Req::RuleDeleteReq(rule::delete::Req {})),
+            Self::SetLabel { current_label, new_label } => {
+                (current_label, rule::req::Req::RuleSetLabelReq(rule::set_label::Req { label: new_label }))
+            }
+        };
+        rule::Req { label, req: Some(req) }
+    }
+}
+
+impl TryFromProto<rule::Res> for RuleResponse {
+    fn try_from_proto(proto: rule::Res) -> Result<Self> {
+        match proto.res {
+            Some(rule::res::Res::RuleDeleteRes(_)) => Ok(Self::Delete),
+            Some(rule::res::Res::RuleSetLabelRes(_)) => Ok(Self::SetLabel),
             None => Err(ConnectionError::MissingResponseField("res").into()),
         }
     }
@@ -22,7 +22,9 @@
 mod common;
 mod concept;
 mod database;
+mod logic;
 mod message;
+mod user;
 
 use crate::Result;
 
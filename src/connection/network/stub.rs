@@ -27,13 +27,13 @@ use tokio::sync::mpsc::{unbounded_channel as unbounded_async, UnboundedSender};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use tonic::{Response, Status, Streaming};
 use typedb_protocol::{
-    cluster_database::Replica, cluster_database_manager, cluster_user, core_database, core_database_manager,
-    server_manager, session, transaction, type_db_client::TypeDbClient as CoreGRPC,
+    cluster_database::Replica, cluster_database_manager, cluster_user, cluster_user_manager, core_database,
+    core_database_manager, server_manager, session, transaction, type_db_client::TypeDbClient as CoreGRPC,
     type_db_cluster_client::TypeDbClusterClient as ClusterGRPC, ClusterDatabase,
 };
 
 use super::channel::{CallCredentials, GRPCChannel};
-use crate::common::{address::Address, error::ConnectionError, Error, Result, StdResult};
+use crate::common::{address::Address, error::ConnectionError, metrics, Error, Result, StdResult};
 
 type TonicResult<T> = StdResult<Response<T>, Status>;
 
@@ -46,7 +46,10 @@ pub(super) struct RPCStub<Channel: GRPCChannel> {
     call_credentials: Option<Arc<CallCredentials>>,
 }
 
-impl<Channel: GRPCChannel> RPCStub<Channel> {
+impl<Channel: GRPCChannel> RPCStub<Channel>
+where
+    Channel::Future: Send,
+{
     pub(super) async fn new(
         address: Address,
         channel: Channel,
@@ -69,7 +72,7 @@ impl<Channel: GRPCChannel> RPCStub<Channel> {
         Ok(self)
     }
 
-    fn address(&self) -> &Address {
+    pub(super) fn address(&self) -> &Address {
         &self.address
     }
 
@@ -80,6 +83,7 @@ impl<Channel: GRPCChannel> RPCStub<Channel> {
         match call(self).await {
             Err(Error::Connection(ConnectionError::ClusterTokenCredentialInvalid())) => {
                 self.renew_token().await?;
+                metrics::token_renewed();
                 call(self).await
             }
             res => res,
@@ -254,6 +258,55 @@ impl<Channel: GRPCChannel> RPCStub<Channel> {
         .await
     }
 
+    pub(super) async fn users_contains(
+        &mut self,
+        req: cluster_user_manager::contains::Req,
+    ) -> Result<cluster_user_manager::contains::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.users_contains(req.clone()))).await
+    }
+
+    pub(super) async fn users_create(
+        &mut self,
+        req: cluster_user_manager::create::Req,
+    ) -> Result<cluster_user_manager::create::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.users_create(req.clone()))).await
+    }
+
+    pub(super) async fn users_delete(
+        &mut self,
+        req: cluster_user_manager::delete::Req,
+    ) -> Result<cluster_user_manager::delete::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.users_delete(req.clone()))).await
+    }
+
+    pub(super) async fn users_all(
+        &mut self,
+        req: cluster_user_manager::all::Req,
+    ) -> Result<cluster_user_manager::all::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.users_all(req.clone()))).await
+    }
+
+    pub(super) async fn users_get(
+        &mut self,
+        req: cluster_user_manager::get::Req,
+    ) -> Result<cluster_user_manager::get::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.users_get(req.clone()))).await
+    }
+
+    pub(super) async fn users_password_set(
+        &mut self,
+        req: cluster_user_manager::password_set::Req,
+    ) -> Result<cluster_user_manager::password_set::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.users_password_set(req.clone()))).await
+    }
+
+    pub(super) async fn user_password_update(
+        &mut self,
+        req: cluster_user::password_update::Req,
+    ) -> Result<cluster_user::password_update::Res> {
+        self.single(|this| Box::pin(this.cluster_grpc.user_password_update(req.clone()))).await
+    }
+
     async fn single<F, R>(&mut self, call: F) -> Result<R>
     where
         for<'a> F: Fn(&'a mut Self) -> BoxFuture<'a, TonicResult<R>> + Send + Sync,
@@ -19,21 +19,19 @@
  * under the License.
  */
 
-use crossbeam::channel::Sender as SyncSender;
+use std::sync::Arc;
+
+use crossbeam::{atomic::AtomicCell, channel::Sender as SyncSender};
 use log::error;
 use tokio::sync::{mpsc::UnboundedSender, oneshot::Sender as AsyncOneshotSender};
 
-use crate::{
-    common::Result,
-    error::{ConnectionError, InternalError},
-    Error,
-};
+use crate::{common::Result, error::InternalError, Error};
 
 #[derive(Debug)]
 pub(super) enum ResponseSink<T> {
     AsyncOneShot(AsyncOneshotSender<Result<T>>),
     BlockingOneShot(SyncSender<Result<T>>),
-    Streamed(UnboundedSender<Result<T>>),
+    Streamed(UnboundedSender<Result<T>>, Option<Arc<AtomicCell<bool>>>),
 }
 
 impl<T> ResponseSink<T> {
@@ -41,16 +39,30 @@ impl<T> ResponseSink<T> {
         let result = match self {
             Self::AsyncOneShot(sink) => sink.send(response).map_err(|_| InternalError::SendError().into()),
             Self::BlockingOneShot(sink) => sink.send(response).map_err(Error::from),
-            Self::Streamed(sink) => sink.send(response).map_err(Error::from),
+            Self::Streamed(sink, _) => sink.send(response).map_err(Error::from),
         };
         if let Err(err) = result {
             error!("{}", err);
         }
     }
 
+    pub(super) fn is_closed(&self) -> bool {
+        match self {
+            Self::Streamed(sink, _) => sink.is_closed(),
+            _ => false,
+        }
+    }
+
+    pub(super) fn is_cancelled(&self) -> bool {
+        match self {
+            Self::Streamed(sink, Some(cancelled)) => sink.is_closed() || cancelled.load(),
+            _ => false,
+        }
+    }
+
     pub(super) fn send(&self, response: Result<T>) {
         let result = match self {
-            Self::Streamed(sink) => sink.send(response).map_err(Error::from),
+            Self::Streamed(sink, _) => sink.send(response).map_err(Error::from),
             _ => unreachable!("attempted to stream over a one-shot callback"),
         };
         if let Err(err) = result {
@@ -58,11 +70,11 @@ impl<T> ResponseSink<T> {
         }
     }
 
-    pub(super) fn error(self, error: ConnectionError) {
+    pub(super) fn error(self, error: Error) {
         match self {
-            Self::AsyncOneShot(sink) => sink.send(Err(error.into())).ok(),
-            Self::BlockingOneShot(sink) => sink.send(Err(error.into())).ok(),
-            Self::Streamed(sink) => sink.send(Err(error.into())).ok(),
+            Self::AsyncOneShot(sink) => sink.send(Err(error)).ok(),
+            Self::BlockingOneShot(sink) => sink.send(Err(error)).ok(),
+            Self::Streamed(sink, _) => sink.send(Err(error)).ok(),
         };
     }
 }
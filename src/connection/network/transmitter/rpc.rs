@@ -19,6 +19,8 @@
  * under the License.
  */
 
+use std::{future::Future, sync::Arc, time::Instant};
+
 use crossbeam::channel::{bounded as bounded_blocking, Receiver as SyncReceiver, Sender as SyncSender};
 use tokio::{
     select,
@@ -27,20 +29,26 @@ use tokio::{
         oneshot::channel as oneshot_async,
     },
 };
+#[cfg(feature = "tracing")]
+use tracing::{instrument::Instrumented, Instrument};
 
 use super::response_sink::ResponseSink;
+#[cfg(feature = "testing")]
+use crate::connection::network::fault::FaultInjectingChannel;
+#[cfg(feature = "tracing")]
+use crate::connection::trace::rpc_span;
 use crate::{
-    common::{address::Address, Result},
+    common::{address::Address, metrics, Result},
     connection::{
         message::{Request, Response},
         network::{
-            channel::{open_encrypted_channel, open_plaintext_channel, GRPCChannel},
+            channel::{open_encrypted_channel, open_plaintext_channel, CallCredentials, GRPCChannel},
             proto::{FromProto, IntoProto, TryFromProto, TryIntoProto},
             stub::RPCStub,
         },
         runtime::BackgroundRuntime,
     },
-    Credential, Error,
+    ConnectionSettings, Credential,
 };
 
 fn oneshot_blocking<T>() -> (SyncSender<T>, SyncReceiver<T>) {
@@ -48,19 +56,27 @@ fn oneshot_blocking<T>() -> (SyncSender<T>, SyncReceiver<T>) {
 }
 
 pub(in crate::connection) struct RPCTransmitter {
-    request_sink: UnboundedSender<(Request, ResponseSink<Response>)>,
+    request_sink: UnboundedSender<(Request, ResponseSink<Response>, RequestContext)>,
     shutdown_sink: UnboundedSender<()>,
 }
 
 impl RPCTransmitter {
-    pub(in crate::connection) fn start_plaintext(address: Address, runtime: &BackgroundRuntime) -> Result<Self> {
+    pub(in crate::connection) fn start_plaintext(
+        address: Address,
+        settings: &ConnectionSettings,
+        runtime: &BackgroundRuntime,
+    ) -> Result<Self> {
         let (request_sink, request_source) = unbounded_async();
         let (shutdown_sink, shutdown_source) = unbounded_async();
+        let settings = settings.clone();
         runtime.run_blocking(async move {
-            let channel = open_plaintext_channel(address.clone());
-            let rpc = RPCStub::new(address.clone(), channel, None).await?;
-            tokio::spawn(Self::dispatcher_loop(rpc, request_source, shutdown_source));
-            Ok::<(), Error>(())
+            let channel = open_plaintext_channel(address.clone(), &settings);
+            #[cfg(feature = "testing")]
+            if let Some(policy) = settings.fault_policy {
+                let channel = FaultInjectingChannel::new(channel, address.clone(), policy);
+                return Self::spawn_dispatcher(address, channel, None, request_source, shutdown_source).await;
+            }
+            Self::spawn_dispatcher(address, channel, None, request_source, shutdown_source).await
         })?;
         Ok(Self { request_sink, shutdown_sink })
     }
@@ -68,28 +84,61 @@ impl RPCTransmitter {
     pub(in crate::connection) fn start_encrypted(
         address: Address,
         credential: Credential,
+        settings: &ConnectionSettings,
         runtime: &BackgroundRuntime,
     ) -> Result<Self> {
         let (request_sink, request_source) = unbounded_async();
         let (shutdown_sink, shutdown_source) = unbounded_async();
-        runtime.run_blocking(async move {
-            let (channel, call_credentials) = open_encrypted_channel(address.clone(), credential)?;
-            let rpc = RPCStub::new(address.clone(), channel, Some(call_credentials)).await?;
-            tokio::spawn(Self::dispatcher_loop(rpc, request_source, shutdown_source));
-            Ok::<(), Error>(())
-        })?;
+        runtime.run_blocking(Self::connect_encrypted(
+            address,
+            credential,
+            settings.clone(),
+            request_source,
+            shutdown_source,
+        ))?;
+        Ok(Self { request_sink, shutdown_sink })
+    }
+
+    pub(in crate::connection) async fn start_encrypted_async(
+        address: Address,
+        credential: Credential,
+        settings: &ConnectionSettings,
+        runtime: &BackgroundRuntime,
+    ) -> Result<Self> {
+        let (request_sink, request_source) = unbounded_async();
+        let (shutdown_sink, shutdown_source) = unbounded_async();
+        runtime
+            .run(Self::connect_encrypted(address, credential, settings.clone(), request_source, shutdown_source))
+            .await?;
         Ok(Self { request_sink, shutdown_sink })
     }
 
+    async fn connect_encrypted(
+        address: Address,
+        credential: Credential,
+        settings: ConnectionSettings,
+        request_source: UnboundedReceiver<(Request, ResponseSink<Response>, RequestContext)>,
+        shutdown_source: UnboundedReceiver<()>,
+    ) -> Result {
+        let (channel, call_credentials) = open_encrypted_channel(address.clone(), credential, &settings)?;
+        #[cfg(feature = "testing")]
+        if let Some(policy) = settings.fault_policy {
+            let channel = FaultInjectingChannel::new(channel, address.clone(), policy);
+            return Self::spawn_dispatcher(address, channel, Some(call_credentials), request_source, shutdown_source)
+                .await;
+        }
+        Self::spawn_dispatcher(address, channel, Some(call_credentials), request_source, shutdown_source).await
+    }
+
     pub(in crate::connection) async fn request_async(&self, request: Request) -> Result<Response> {
         let (response_sink, response) = oneshot_async();
-        self.request_sink.send((request, ResponseSink::AsyncOneShot(response_sink)))?;
+        self.request_sink.send((request, ResponseSink::AsyncOneShot(response_sink), RequestContext::current()))?;
         response.await?
     }
 
     pub(in crate::connection) fn request_blocking(&self, request: Request) -> Result<Response> {
         let (response_sink, response) = oneshot_blocking();
-        self.request_sink.send((request, ResponseSink::BlockingOneShot(response_sink)))?;
+        self.request_sink.send((request, ResponseSink::BlockingOneShot(response_sink), RequestContext::current()))?;
         response.recv()?
     }
 
@@ -97,24 +146,49 @@ impl RPCTransmitter {
         self.shutdown_sink.send(()).map_err(Into::into)
     }
 
+    async fn spawn_dispatcher<Channel: GRPCChannel>(
+        address: Address,
+        channel: Channel,
+        call_credentials: Option<Arc<CallCredentials>>,
+        request_source: UnboundedReceiver<(Request, ResponseSink<Response>, RequestContext)>,
+        shutdown_source: UnboundedReceiver<()>,
+    ) -> Result
+    where
+        Channel::Future: Send,
+    {
+        let rpc = RPCStub::new(address, channel, call_credentials).await?;
+        tokio::spawn(Self::dispatcher_loop(rpc, request_source, shutdown_source));
+        Ok(())
+    }
+
     async fn dispatcher_loop<Channel: GRPCChannel>(
         rpc: RPCStub<Channel>,
-        mut request_source: UnboundedReceiver<(Request, ResponseSink<Response>)>,
+        mut request_source: UnboundedReceiver<(Request, ResponseSink<Response>, RequestContext)>,
         mut shutdown_signal: UnboundedReceiver<()>,
-    ) {
-        while let Some((request, response_sink)) = select! {
+    ) where
+        Channel::Future: Send,
+    {
+        while let Some((request, response_sink, context)) = select! {
             request = request_source.recv() => request,
             _ = shutdown_signal.recv() => None,
         } {
             let rpc = rpc.clone();
-            tokio::spawn(async move {
+            let context = context.enter_rpc(&request, rpc.address());
+            tokio::spawn(context.instrument(async move {
+                let request_name = request.name();
+                let start = Instant::now();
+                metrics::request_started(request_name);
                 let response = Self::send_request(rpc, request).await;
+                metrics::request_finished(request_name, start.elapsed());
                 response_sink.finish(response);
-            });
+            }));
         }
     }
 
-    async fn send_request<Channel: GRPCChannel>(mut rpc: RPCStub<Channel>, request: Request) -> Result<Response> {
+    async fn send_request<Channel: GRPCChannel>(mut rpc: RPCStub<Channel>, request: Request) -> Result<Response>
+    where
+        Channel::Future: Send,
+    {
         match request {
             Request::ServersAll => rpc.servers_all(request.try_into_proto()?).await.and_then(Response::try_from_proto),
 
@@ -156,6 +230,55 @@ impl RPCTransmitter {
                 let (request_sink, response_source) = rpc.transaction(transaction_request.into_proto()).await?;
                 Ok(Response::TransactionOpen { request_sink, response_source })
             }
+
+            Request::UsersContains { .. } => {
+                rpc.users_contains(request.try_into_proto()?).await.map(Response::from_proto)
+            }
+            Request::UsersCreate { .. } => rpc.users_create(request.try_into_proto()?).await.map(Response::from_proto),
+            Request::UsersDelete { .. } => rpc.users_delete(request.try_into_proto()?).await.map(Response::from_proto),
+            Request::UsersAll => rpc.users_all(request.try_into_proto()?).await.map(Response::from_proto),
+            Request::UsersGet { .. } => rpc.users_get(request.try_into_proto()?).await.map(Response::from_proto),
+            Request::UsersPasswordSet { .. } => {
+                rpc.users_password_set(request.try_into_proto()?).await.map(Response::from_proto)
+            }
+
+            Request::UserPasswordUpdate { .. } => {
+                rpc.user_password_update(request.try_into_proto()?).await.map(Response::from_proto)
+            }
         }
     }
 }
+
+struct RequestContext {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+}
+
+impl RequestContext {
+    fn current() -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    fn enter_rpc(self, request: &Request, address: &Address) -> Self {
+        Self { span: rpc_span(&self.span, request, address) }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn enter_rpc(self, _request: &Request, _address: &Address) -> Self {
+        self
+    }
+
+    #[cfg(feature = "tracing")]
+    fn instrument<F: Future>(self, task: F) -> Instrumented<F> {
+        task.instrument(self.span)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn instrument<F: Future>(self, task: F) -> F {
+        task
+    }
+}
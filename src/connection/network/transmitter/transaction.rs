@@ -21,46 +21,59 @@
 
 use std::{
     collections::HashMap,
-    sync::{Arc, RwLock},
+    mem,
+    sync::{Arc, Mutex, RwLock},
     time::Duration,
 };
 
 use crossbeam::atomic::AtomicCell;
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{future, Stream, StreamExt, TryStreamExt};
 use log::error;
 use prost::Message;
 use tokio::{
     select,
     sync::{
-        mpsc::{error::SendError, unbounded_channel as unbounded_async, UnboundedReceiver, UnboundedSender},
+        mpsc::{unbounded_channel as unbounded_async, UnboundedReceiver, UnboundedSender},
         oneshot::channel as oneshot_async,
     },
     time::{sleep_until, Instant},
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
-use tonic::Streaming;
+use tonic::{Status, Streaming};
 use typedb_protocol::transaction::{self, server::Server, stream::State};
 
 use super::response_sink::ResponseSink;
+#[cfg(feature = "testing")]
+use crate::connection::network::fault::inject_transaction_faults;
+#[cfg(feature = "tracing")]
+use crate::connection::trace::record_request_id;
 use crate::{
-    common::{error::ConnectionError, RequestID, Result},
+    common::{error::ConnectionError, metrics, RequestID, Result, StdResult},
     connection::{
         message::{TransactionRequest, TransactionResponse},
         network::proto::{IntoProto, TryFromProto},
         runtime::BackgroundRuntime,
     },
+    ConnectionSettings, Error,
 };
 
+type CloseCallback = Box<dyn FnOnce(Option<Error>) + Send>;
+
+enum CloseState {
+    Open(Vec<CloseCallback>),
+    Closed(Option<Error>),
+}
+
 pub(in crate::connection) struct TransactionTransmitter {
-    request_sink: UnboundedSender<(TransactionRequest, Option<ResponseSink<TransactionResponse>>)>,
+    request_sink: UnboundedSender<(transaction::Req, Option<ResponseSink<TransactionResponse>>)>,
     is_open: Arc<AtomicCell<bool>>,
+    close_state: Arc<Mutex<CloseState>>,
     shutdown_sink: UnboundedSender<()>,
 }
 
 impl Drop for TransactionTransmitter {
     fn drop(&mut self) {
-        self.is_open.store(false);
-        self.shutdown_sink.send(()).ok();
+        self.force_close();
     }
 }
 
@@ -69,70 +82,123 @@ impl TransactionTransmitter {
         background_runtime: &BackgroundRuntime,
         request_sink: UnboundedSender<transaction::Client>,
         response_source: Streaming<transaction::Server>,
+        settings: &ConnectionSettings,
     ) -> Self {
         let (buffer_sink, buffer_source) = unbounded_async();
         let (shutdown_sink, shutdown_source) = unbounded_async();
         let is_open = Arc::new(AtomicCell::new(true));
+        let close_state = Arc::new(Mutex::new(CloseState::Open(Vec::new())));
+        metrics::transaction_opened();
+        #[cfg(feature = "testing")]
+        let response_source = inject_transaction_faults(response_source, settings.fault_policy.clone());
         background_runtime.spawn(Self::start_workers(
             buffer_sink.clone(),
             buffer_source,
             request_sink,
             response_source,
             is_open.clone(),
+            close_state.clone(),
             shutdown_source,
+            settings.transaction_dispatch_interval,
+            settings.transaction_max_message_len,
         ));
-        Self { request_sink: buffer_sink, is_open, shutdown_sink }
+        Self { request_sink: buffer_sink, is_open, close_state, shutdown_sink }
     }
 
     pub(in crate::connection) fn is_open(&self) -> bool {
         self.is_open.load()
     }
 
+    pub(in crate::connection) fn force_close(&self) {
+        self.is_open.store(false);
+        self.shutdown_sink.send(()).ok();
+    }
+
+    pub(in crate::connection) fn on_close(&self, callback: impl FnOnce(Option<Error>) + Send + 'static) {
+        let close_reason = match &mut *self.close_state.lock().unwrap() {
+            CloseState::Open(callbacks) => {
+                callbacks.push(Box::new(callback));
+                return;
+            }
+            CloseState::Closed(close_reason) => close_reason.clone(),
+        };
+        callback(close_reason);
+    }
+
+    pub(in crate::connection) async fn wait_closed(&self) -> Option<Error> {
+        let (close_sink, close_source) = oneshot_async();
+        self.on_close(move |close_reason| {
+            close_sink.send(close_reason).ok();
+        });
+        close_source.await.unwrap_or_default()
+    }
+
     pub(in crate::connection) async fn single(&self, req: TransactionRequest) -> Result<TransactionResponse> {
         if !self.is_open() {
             return Err(ConnectionError::SessionIsClosed().into());
         }
         let (res_sink, recv) = oneshot_async();
-        self.request_sink.send((req, Some(ResponseSink::AsyncOneShot(res_sink))))?;
+        self.request_sink.send((encode_request(req), Some(ResponseSink::AsyncOneShot(res_sink))))?;
         recv.await?.map(Into::into)
     }
 
     pub(in crate::connection) fn stream(
         &self,
         req: TransactionRequest,
+    ) -> Result<impl Stream<Item = Result<TransactionResponse>>> {
+        self.cancellable_stream(req, None)
+    }
+
+    pub(in crate::connection) fn cancellable_stream(
+        &self,
+        req: TransactionRequest,
+        cancelled: Option<Arc<AtomicCell<bool>>>,
     ) -> Result<impl Stream<Item = Result<TransactionResponse>>> {
         if !self.is_open() {
             return Err(ConnectionError::SessionIsClosed().into());
         }
         let (res_part_sink, recv) = unbounded_async();
-        self.request_sink.send((req, Some(ResponseSink::Streamed(res_part_sink))))?;
-        Ok(UnboundedReceiverStream::new(recv).map_ok(Into::into))
+        let callback = ResponseSink::Streamed(res_part_sink, cancelled.clone());
+        self.request_sink.send((encode_request(req), Some(callback)))?;
+        Ok(UnboundedReceiverStream::new(recv)
+            .take_while(move |_| future::ready(!cancelled.as_ref().map_or(false, |cancelled| cancelled.load())))
+            .map_ok(Into::into))
     }
 
     async fn start_workers(
-        queue_sink: UnboundedSender<(TransactionRequest, Option<ResponseSink<TransactionResponse>>)>,
-        queue_source: UnboundedReceiver<(TransactionRequest, Option<ResponseSink<TransactionResponse>>)>,
+        queue_sink: UnboundedSender<(transaction::Req, Option<ResponseSink<TransactionResponse>>)>,
+        queue_source: UnboundedReceiver<(transaction::Req, Option<ResponseSink<TransactionResponse>>)>,
         request_sink: UnboundedSender<transaction::Client>,
-        response_source: Streaming<transaction::Server>,
+        response_source: impl Stream<Item = StdResult<transaction::Server, Status>> + Unpin + Send + 'static,
         is_open: Arc<AtomicCell<bool>>,
+        close_state: Arc<Mutex<CloseState>>,
         shutdown_signal: UnboundedReceiver<()>,
+        dispatch_interval: Duration,
+        max_message_len: usize,
     ) {
-        let collector = ResponseCollector { request_sink: queue_sink, callbacks: Default::default(), is_open };
-        tokio::spawn(Self::dispatch_loop(queue_source, request_sink, collector.clone(), shutdown_signal));
+        let collector =
+            ResponseCollector { request_sink: queue_sink, callbacks: Default::default(), is_open, close_state };
+        tokio::spawn(Self::dispatch_loop(
+            queue_source,
+            request_sink,
+            collector.clone(),
+            shutdown_signal,
+            dispatch_interval,
+            max_message_len,
+        ));
         tokio::spawn(Self::listen_loop(response_source, collector));
     }
 
     async fn dispatch_loop(
-        mut request_source: UnboundedReceiver<(TransactionRequest, Option<ResponseSink<TransactionResponse>>)>,
+        mut request_source: UnboundedReceiver<(transaction::Req, Option<ResponseSink<TransactionResponse>>)>,
         request_sink: UnboundedSender<transaction::Client>,
         mut collector: ResponseCollector,
         mut shutdown_signal: UnboundedReceiver<()>,
+        dispatch_interval: Duration,
+        max_message_len: usize,
     ) {
-        const MAX_GRPC_MESSAGE_LEN: usize = 1_000_000;
-        const DISPATCH_INTERVAL: Duration = Duration::from_millis(3);
-
         let mut request_buffer = TransactionRequestBuffer::default();
-        let mut next_dispatch = Instant::now() + DISPATCH_INTERVAL;
+        let mut next_dispatch = Instant::now() + dispatch_interval;
         loop {
             select! { biased;
                 _ = shutdown_signal.recv() => {
@@ -141,22 +207,24 @@ impl TransactionTransmitter {
                     }
                     break;
                 }
-                _ = sleep_until(next_dispatch) => {
+                _ = sleep_until(next_dispatch), if !dispatch_interval.is_zero() => {
                     if !request_buffer.is_empty() {
                         request_sink.send(request_buffer.take()).unwrap();
                     }
-                    next_dispatch = Instant::now() + DISPATCH_INTERVAL;
+                    next_dispatch = Instant::now() + dispatch_interval;
                 }
                 recv = request_source.recv() => {
                     if let Some((request, callback)) = recv {
-                        let request = request.into_proto();
                         if let Some(callback) = callback {
                             collector.register(request.req_id.clone().into(), callback);
                         }
-                        if request_buffer.len() + request.encoded_len() > MAX_GRPC_MESSAGE_LEN {
+                        if request_buffer.len() + request.encoded_len() > max_message_len {
                             request_sink.send(request_buffer.take()).unwrap();
                         }
                         request_buffer.push(request);
+                        if dispatch_interval.is_zero() {
+                            request_sink.send(request_buffer.take()).unwrap();
+                        }
                     } else {
                         break;
                     }
@@ -165,19 +233,33 @@ impl TransactionTransmitter {
         }
     }
 
-    async fn listen_loop(mut grpc_source: Streaming<transaction::Server>, collector: ResponseCollector) {
+    async fn listen_loop(
+        mut grpc_source: impl Stream<Item = StdResult<transaction::Server, Status>> + Unpin,
+        collector: ResponseCollector,
+    ) {
         loop {
             match grpc_source.next().await {
                 Some(Ok(message)) => collector.collect(message).await,
                 Some(Err(err)) => {
-                    break collector.close(ConnectionError::TransactionIsClosedWithErrors(err.to_string())).await
+                    let error = match Error::from(err.clone()) {
+                        Error::Other(_) => ConnectionError::TransactionIsClosedWithErrors(err.to_string()).into(),
+                        error => error,
+                    };
+                    break collector.close(Some(error)).await;
                 }
-                None => break collector.close(ConnectionError::TransactionIsClosed()).await,
+                None => break collector.close(None).await,
             }
         }
     }
 }
 
+fn encode_request(request: TransactionRequest) -> transaction::Req {
+    let request = request.into_proto();
+    #[cfg(feature = "tracing")]
+    record_request_id(&request.req_id.clone().into());
+    request
+}
+
 #[derive(Default)]
 struct TransactionRequestBuffer {
     reqs: Vec<transaction::Req>,
@@ -199,6 +281,7 @@ impl TransactionRequestBuffer {
     }
 
     fn take(&mut self) -> transaction::Client {
+        metrics::transaction_batch_sent(self.reqs.len(), self.len);
         self.len = 0;
         transaction::Client { reqs: std::mem::take(&mut self.reqs) }
     }
@@ -206,9 +289,10 @@ impl TransactionRequestBuffer {
 
 #[derive(Clone)]
 struct ResponseCollector {
-    request_sink: UnboundedSender<(TransactionRequest, Option<ResponseSink<TransactionResponse>>)>,
+    request_sink: UnboundedSender<(transaction::Req, Option<ResponseSink<TransactionResponse>>)>,
     callbacks: Arc<RwLock<HashMap<RequestID, ResponseSink<TransactionResponse>>>>,
     is_open: Arc<AtomicCell<bool>>,
+    close_state: Arc<Mutex<CloseState>>,
 }
 
 impl ResponseCollector {
@@ -246,17 +330,24 @@ impl ResponseCollector {
                         self.callbacks.write().unwrap().remove(&request_id);
                     }
                     State::Continue => {
-                        match self.request_sink.send((TransactionRequest::Stream { request_id }, None)) {
-                            Err(SendError((TransactionRequest::Stream { request_id }, None))) => {
-                                let callback = self.callbacks.write().unwrap().remove(&request_id).unwrap();
-                                callback.error(ConnectionError::TransactionIsClosed());
-                            }
-                            _ => (),
+                        let is_cancelled =
+                            self.callbacks.read().unwrap().get(&request_id).map_or(true, ResponseSink::is_cancelled);
+                        if is_cancelled {
+                            // Withholding the continuation stops the server from computing further answers,
+                            // but it keeps the query's iterator open until the transaction closes.
+                            self.callbacks.write().unwrap().remove(&request_id);
+                            return;
+                        }
+                        let request = TransactionRequest::Stream { request_id: request_id.clone() }.into_proto();
+                        if self.request_sink.send((request, None)).is_err() {
+                            let callback = self.callbacks.write().unwrap().remove(&request_id).unwrap();
+                            callback.error(ConnectionError::TransactionIsClosed().into());
                         }
                     }
                 }
             }
             Some(_) => match self.callbacks.read().unwrap().get(&request_id) {
+                Some(sink) if sink.is_closed() || sink.is_cancelled() => (),
                 Some(sink) => sink.send(TransactionResponse::try_from_proto(res_part)),
                 _ => error!("{}", ConnectionError::UnknownRequestId(request_id)),
             },
@@ -264,11 +355,20 @@ impl ResponseCollector {
         }
     }
 
-    async fn close(self, error: ConnectionError) {
+    async fn close(self, close_reason: Option<Error>) {
         self.is_open.store(false);
-        let mut listeners = std::mem::take(&mut *self.callbacks.write().unwrap());
+        let error = close_reason.clone().unwrap_or_else(|| ConnectionError::TransactionIsClosed().into());
+        let mut listeners = mem::take(&mut *self.callbacks.write().unwrap());
         for (_, listener) in listeners.drain() {
             listener.error(error.clone());
         }
+        let close_state =
+            mem::replace(&mut *self.close_state.lock().unwrap(), CloseState::Closed(close_reason.clone()));
+        if let CloseState::Open(callbacks) = close_state {
+            metrics::transaction_closed();
+            for callback in callbacks {
+                callback(close_reason.clone());
+            }
+        }
     }
 }
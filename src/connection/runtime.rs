@@ -19,12 +19,16 @@
  * under the License.
  */
 
-use std::{future::Future, thread};
+use std::{future::Future, thread, time::Duration};
 
 use crossbeam::{atomic::AtomicCell, channel::bounded as bounded_blocking};
 use tokio::{
     runtime,
-    sync::mpsc::{unbounded_channel as unbounded_async, UnboundedSender},
+    sync::{
+        mpsc::{unbounded_channel as unbounded_async, UnboundedSender},
+        oneshot::channel as oneshot_async,
+    },
+    time,
 };
 
 use crate::common::Result;
@@ -78,6 +82,28 @@ impl BackgroundRuntime {
         });
         response.recv().unwrap()
     }
+
+    pub(super) async fn run<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (response_sink, response) = oneshot_async();
+        self.async_runtime_handle.spawn(async move {
+            response_sink.send(future.await).ok();
+        });
+        response.await.unwrap()
+    }
+
+    pub(super) async fn sleep(&self, duration: Duration) {
+        // The timer runs on the background runtime, so the caller may be on any executor.
+        let (wake_sink, wake) = oneshot_async();
+        self.async_runtime_handle.spawn(async move {
+            time::sleep(duration).await;
+            wake_sink.send(()).ok();
+        });
+        wake.await.ok();
+    }
 }
 
 impl Drop for BackgroundRuntime {
@@ -0,0 +1,139 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use futures::{Stream, StreamExt};
+use opentelemetry::{global, propagation::Injector};
+use tonic::metadata::{MetadataKey, MetadataMap};
+use tracing::{field, info_span, Span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use super::message::{QueryRequest, QueryResponse, Request, TransactionRequest};
+use crate::{
+    common::{address::Address, RequestID, Result, SessionID},
+    TransactionType,
+};
+
+pub(super) fn rpc_span(parent: &Span, request: &Request, address: &Address) -> Span {
+    let span = info_span!(
+        parent: parent,
+        "typedb.rpc",
+        otel.name = request.name(),
+        replica_address = %address,
+        database = field::Empty,
+        session_id = field::Empty,
+    );
+    match request {
+        Request::DatabasesContains { database_name }
+        | Request::DatabaseCreate { database_name }
+        | Request::DatabaseGet { database_name }
+        | Request::DatabaseSchema { database_name }
+        | Request::DatabaseTypeSchema { database_name }
+        | Request::DatabaseRuleSchema { database_name }
+        | Request::DatabaseDelete { database_name }
+        | Request::SessionOpen { database_name, .. } => {
+            span.record("database", database_name.as_str());
+        }
+        Request::SessionClose { session_id }
+        | Request::SessionPulse { session_id }
+        | Request::Transaction(TransactionRequest::Open { session_id, .. }) => {
+            span.record("session_id", field::display(session_id));
+        }
+        _ => (),
+    }
+    span
+}
+
+pub(crate) fn transaction_span(database: &str, transaction_type: TransactionType) -> Span {
+    info_span!(
+        "typedb.transaction",
+        database,
+        transaction_type = ?transaction_type,
+        session_id = field::Empty,
+        replica_address = field::Empty,
+    )
+}
+
+pub(super) fn record_transaction_open(session_id: &SessionID, address: &Address) {
+    let span = Span::current();
+    span.record("session_id", field::display(session_id));
+    span.record("replica_address", field::display(address));
+}
+
+pub(super) fn query_span(parent: &Span, request: &QueryRequest) -> Span {
+    info_span!(
+        parent: parent,
+        "typedb.query",
+        otel.name = query_name(request),
+        request_id = field::Empty,
+        answer_count = field::Empty,
+    )
+}
+
+fn query_name(request: &QueryRequest) -> &'static str {
+    match request {
+        QueryRequest::Define { .. } => "define",
+        QueryRequest::Undefine { .. } => "undefine",
+        QueryRequest::Delete { .. } => "delete",
+        QueryRequest::Match { .. } => "match",
+        QueryRequest::Insert { .. } => "insert",
+        QueryRequest::Update { .. } => "update",
+        QueryRequest::MatchAggregate { .. } => "match_aggregate",
+        QueryRequest::Explain { .. } => "explain",
+        QueryRequest::MatchGroup { .. } => "match_group",
+        QueryRequest::MatchGroupAggregate { .. } => "match_group_aggregate",
+    }
+}
+
+pub(super) fn record_request_id(request_id: &RequestID) {
+    Span::current().record("request_id", field::display(request_id));
+}
+
+pub(super) fn record_answer_count(span: &Span, response: &QueryResponse) {
+    span.record("answer_count", response.answer_count());
+}
+
+pub(super) fn count_answers(
+    span: Span,
+    stream: impl Stream<Item = Result<QueryResponse>>,
+) -> impl Stream<Item = Result<QueryResponse>> {
+    let mut count = 0;
+    stream.inspect(move |response| {
+        if let Ok(response) = response {
+            count += response.answer_count();
+            span.record("answer_count", count);
+        }
+    })
+}
+
+pub(super) fn inject_context(metadata: &mut MetadataMap) {
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&context, &mut MetadataInjector(metadata)));
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (MetadataKey::from_bytes(key.as_bytes()), value.parse()) {
+            self.0.insert(key, value);
+        }
+    }
+}
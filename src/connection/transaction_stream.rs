@@ -19,23 +19,40 @@
  * under the License.
  */
 
-use std::{fmt, iter};
+use std::{fmt, iter, sync::Arc};
 
+use crossbeam::atomic::AtomicCell;
 use futures::{stream, Stream, StreamExt};
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
+use typeql_lang::pattern::{Conjunction, ThingVariable};
 
 use super::network::transmitter::TransactionTransmitter;
+#[cfg(feature = "tracing")]
+use super::trace::{count_answers, query_span, record_answer_count};
 use crate::{
-    answer::{ConceptMap, Numeric},
-    common::Result,
-    connection::message::{QueryRequest, QueryResponse, TransactionRequest, TransactionResponse},
+    answer::{ConceptMap, ConceptMapGroup, Explainable, Numeric, NumericGroup},
+    common::{metrics, Result},
+    concept::{
+        attribute::ValueType, Attribute, AttributeType, EntityType, Relation, RelationType, RoleType, SchemaException,
+        Thing, ThingType,
+    },
+    connection::message::{
+        ConceptRequest, ConceptResponse, LogicRequest, LogicResponse, QueryRequest, QueryResponse, RuleRequest,
+        RuleResponse, ThingRequest, ThingResponse, ThingTypeRequest, ThingTypeResponse, TransactionRequest,
+        TransactionResponse,
+    },
     error::InternalError,
-    Options, TransactionType,
+    logic::{Explanation, Rule},
+    Error, Options, TransactionType,
 };
 
 pub(crate) struct TransactionStream {
     type_: TransactionType,
     options: Options,
     transaction_transmitter: TransactionTransmitter,
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
 }
 
 impl TransactionStream {
@@ -44,7 +61,13 @@ impl TransactionStream {
         options: Options,
         transaction_transmitter: TransactionTransmitter,
     ) -> Self {
-        Self { type_, options, transaction_transmitter }
+        Self {
+            type_,
+            options,
+            transaction_transmitter,
+            #[cfg(feature = "tracing")]
+            span: tracing::Span::current(),
+        }
     }
 
     pub(crate) fn is_open(&self) -> bool {
@@ -69,6 +92,18 @@ impl TransactionStream {
         Ok(())
     }
 
+    pub(crate) async fn close(&self) -> Result {
+        self.transaction_transmitter.force_close();
+        match self.transaction_transmitter.wait_closed().await {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn on_close(&self, callback: impl FnOnce(Option<Error>) + Send + 'static) {
+        self.transaction_transmitter.on_close(callback)
+    }
+
     pub(crate) async fn define(&self, query: String, options: Options) -> Result {
         self.single(TransactionRequest::Query(QueryRequest::Define { query, options })).await?;
         Ok(())
@@ -85,16 +120,26 @@ impl TransactionStream {
     }
 
     pub(crate) fn match_(&self, query: String, options: Options) -> Result<impl Stream<Item = Result<ConceptMap>>> {
-        let stream = self.query_stream(QueryRequest::Match { query, options })?;
-        Ok(stream.flat_map(|result| match result {
+        Ok(self.match_cancellable(query, options)?.0)
+    }
+
+    pub(crate) fn match_cancellable(
+        &self,
+        query: String,
+        options: Options,
+    ) -> Result<(impl Stream<Item = Result<ConceptMap>>, QueryHandle)> {
+        let handle = QueryHandle::new();
+        let stream = self.query_stream(QueryRequest::Match { query, options }, Some(&handle))?;
+        let stream = stream.flat_map(|result| match result {
             Ok(QueryResponse::Match { answers }) => stream_iter(answers.into_iter().map(Ok)),
             Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
             Err(err) => stream_once(Err(err)),
-        }))
+        });
+        Ok((stream, handle))
     }
 
     pub(crate) fn insert(&self, query: String, options: Options) -> Result<impl Stream<Item = Result<ConceptMap>>> {
-        let stream = self.query_stream(QueryRequest::Insert { query, options })?;
+        let stream = self.query_stream(QueryRequest::Insert { query, options }, None)?;
         Ok(stream.flat_map(|result| match result {
             Ok(QueryResponse::Insert { answers }) => stream_iter(answers.into_iter().map(Ok)),
             Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
@@ -103,7 +148,7 @@ impl TransactionStream {
     }
 
     pub(crate) fn update(&self, query: String, options: Options) -> Result<impl Stream<Item = Result<ConceptMap>>> {
-        let stream = self.query_stream(QueryRequest::Update { query, options })?;
+        let stream = self.query_stream(QueryRequest::Update { query, options }, None)?;
         Ok(stream.flat_map(|result| match result {
             Ok(QueryResponse::Update { answers }) => stream_iter(answers.into_iter().map(Ok)),
             Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
@@ -118,24 +163,440 @@ impl TransactionStream {
         }
     }
 
+    pub(crate) fn match_group(
+        &self,
+        query: String,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<ConceptMapGroup>>> {
+        let stream = self.query_stream(QueryRequest::MatchGroup { query, options }, Some(&QueryHandle::new()))?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(QueryResponse::MatchGroup { answers }) => stream_iter(answers.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn match_group_aggregate(
+        &self,
+        query: String,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<NumericGroup>>> {
+        let stream =
+            self.query_stream(QueryRequest::MatchGroupAggregate { query, options }, Some(&QueryHandle::new()))?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(QueryResponse::MatchGroupAggregate { answers }) => stream_iter(answers.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn explain(
+        &self,
+        explainable: &Explainable,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<Explanation>>> {
+        let stream = self.query_stream(
+            QueryRequest::Explain { explainable_id: explainable.id, options },
+            Some(&QueryHandle::new()),
+        )?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(QueryResponse::Explain { answers }) => stream_iter(answers.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn get_thing_type(&self, label: String) -> Result<Option<ThingType>> {
+        match self.concept_single(ConceptRequest::GetThingType { label }).await? {
+            ConceptResponse::GetThingType { thing_type } => Ok(thing_type),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn get_thing(&self, iid: Vec<u8>) -> Result<Option<Thing>> {
+        match self.concept_single(ConceptRequest::GetThing { iid }).await? {
+            ConceptResponse::GetThing { thing } => Ok(thing),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn put_entity_type(&self, label: String) -> Result<EntityType> {
+        match self.concept_single(ConceptRequest::PutEntityType { label }).await? {
+            ConceptResponse::PutEntityType { entity_type } => Ok(entity_type),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn put_relation_type(&self, label: String) -> Result<RelationType> {
+        match self.concept_single(ConceptRequest::PutRelationType { label }).await? {
+            ConceptResponse::PutRelationType { relation_type } => Ok(relation_type),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn put_attribute_type(&self, label: String, value_type: ValueType) -> Result<AttributeType> {
+        match self.concept_single(ConceptRequest::PutAttributeType { label, value_type }).await? {
+            ConceptResponse::PutAttributeType { attribute_type } => Ok(attribute_type),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) fn get_schema_exceptions(&self) -> Result<impl Stream<Item = Result<SchemaException>>> {
+        let stream = self.concept_stream(ConceptRequest::GetSchemaExceptions)?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ConceptResponse::GetSchemaExceptions { exceptions }) => stream_iter(exceptions.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn thing_type_delete(&self, thing_type: ThingType) -> Result {
+        match self.thing_type_single(ThingTypeRequest::Delete { thing_type }).await? {
+            ThingTypeResponse::Delete => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn thing_type_set_label(&self, thing_type: ThingType, new_label: String) -> Result {
+        match self.thing_type_single(ThingTypeRequest::SetLabel { thing_type, new_label }).await? {
+            ThingTypeResponse::SetLabel => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn thing_type_set_abstract(&self, thing_type: ThingType) -> Result {
+        match self.thing_type_single(ThingTypeRequest::SetAbstract { thing_type }).await? {
+            ThingTypeResponse::SetAbstract => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn thing_type_get_supertype(&self, thing_type: ThingType) -> Result<Option<ThingType>> {
+        match self.thing_type_single(ThingTypeRequest::GetSupertype { thing_type }).await? {
+            ThingTypeResponse::GetSupertype { supertype } => Ok(supertype),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) fn thing_type_get_supertypes(
+        &self,
+        thing_type: ThingType,
+    ) -> Result<impl Stream<Item = Result<ThingType>>> {
+        let stream = self.thing_type_stream(ThingTypeRequest::GetSupertypes { thing_type })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingTypeResponse::GetSupertypes { supertypes }) => stream_iter(supertypes.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn thing_type_get_subtypes(
+        &self,
+        thing_type: ThingType,
+    ) -> Result<impl Stream<Item = Result<ThingType>>> {
+        let stream = self.thing_type_stream(ThingTypeRequest::GetSubtypes { thing_type })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingTypeResponse::GetSubtypes { subtypes }) => stream_iter(subtypes.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn thing_type_get_instances(&self, thing_type: ThingType) -> Result<impl Stream<Item = Result<Thing>>> {
+        let stream = self.thing_type_stream(ThingTypeRequest::GetInstances { thing_type })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingTypeResponse::GetInstances { instances }) => stream_iter(instances.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn thing_type_get_owns(
+        &self,
+        thing_type: ThingType,
+        value_type: Option<ValueType>,
+        keys_only: bool,
+    ) -> Result<impl Stream<Item = Result<AttributeType>>> {
+        let stream = self.thing_type_stream(ThingTypeRequest::GetOwns { thing_type, value_type, keys_only })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingTypeResponse::GetOwns { attribute_types }) => stream_iter(attribute_types.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn thing_type_get_plays(&self, thing_type: ThingType) -> Result<impl Stream<Item = Result<RoleType>>> {
+        let stream = self.thing_type_stream(ThingTypeRequest::GetPlays { thing_type })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingTypeResponse::GetPlays { role_types }) => stream_iter(role_types.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn thing_delete(&self, iid: Vec<u8>) -> Result {
+        match self.thing_single(ThingRequest::Delete { iid }).await? {
+            ThingResponse::Delete => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) fn thing_get_has(
+        &self,
+        iid: Vec<u8>,
+        attribute_types: Vec<AttributeType>,
+        keys_only: bool,
+    ) -> Result<impl Stream<Item = Result<Attribute>>> {
+        let stream = self.thing_stream(ThingRequest::GetHas { iid, attribute_types, keys_only })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingResponse::GetHas { attributes }) => stream_iter(attributes.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn thing_set_has(&self, iid: Vec<u8>, attribute: Attribute) -> Result {
+        match self.thing_single(ThingRequest::SetHas { iid, attribute }).await? {
+            ThingResponse::SetHas => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn thing_unset_has(&self, iid: Vec<u8>, attribute: Attribute) -> Result {
+        match self.thing_single(ThingRequest::UnsetHas { iid, attribute }).await? {
+            ThingResponse::UnsetHas => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) fn thing_get_relations(
+        &self,
+        iid: Vec<u8>,
+        role_types: Vec<RoleType>,
+    ) -> Result<impl Stream<Item = Result<Relation>>> {
+        let stream = self.thing_stream(ThingRequest::GetRelations { iid, role_types })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingResponse::GetRelations { relations }) => stream_iter(relations.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn thing_get_playing(&self, iid: Vec<u8>) -> Result<impl Stream<Item = Result<RoleType>>> {
+        let stream = self.thing_stream(ThingRequest::GetPlaying { iid })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingResponse::GetPlaying { role_types }) => stream_iter(role_types.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) fn relation_get_players_by_role_type(
+        &self,
+        iid: Vec<u8>,
+    ) -> Result<impl Stream<Item = Result<(RoleType, Thing)>>> {
+        let stream = self.thing_stream(ThingRequest::RelationGetPlayersByRoleType { iid })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingResponse::RelationGetPlayersByRoleType { role_types_with_players }) => {
+                stream_iter(role_types_with_players.into_iter().map(Ok))
+            }
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn relation_add_player(&self, iid: Vec<u8>, role_type: RoleType, player: Thing) -> Result {
+        match self.thing_single(ThingRequest::RelationAddPlayer { iid, role_type, player }).await? {
+            ThingResponse::RelationAddPlayer => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn relation_remove_player(&self, iid: Vec<u8>, role_type: RoleType, player: Thing) -> Result {
+        match self.thing_single(ThingRequest::RelationRemovePlayer { iid, role_type, player }).await? {
+            ThingResponse::RelationRemovePlayer => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) fn attribute_get_owners(
+        &self,
+        iid: Vec<u8>,
+        thing_type: Option<ThingType>,
+    ) -> Result<impl Stream<Item = Result<Thing>>> {
+        let stream = self.thing_stream(ThingRequest::AttributeGetOwners { iid, thing_type })?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(ThingResponse::AttributeGetOwners { owners }) => stream_iter(owners.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn get_rule(&self, label: String) -> Result<Option<Rule>> {
+        match self.logic_single(LogicRequest::GetRule { label }).await? {
+            LogicResponse::GetRule { rule } => Ok(rule),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn put_rule(&self, label: String, when: Conjunction, then: ThingVariable) -> Result<Rule> {
+        match self.logic_single(LogicRequest::PutRule { label, when, then }).await? {
+            LogicResponse::PutRule { rule } => Ok(rule),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) fn get_rules(&self) -> Result<impl Stream<Item = Result<Rule>>> {
+        let stream = self.logic_stream(LogicRequest::GetRules)?;
+        Ok(stream.flat_map(|result| match result {
+            Ok(LogicResponse::GetRules { rules }) => stream_iter(rules.into_iter().map(Ok)),
+            Ok(other) => stream_once(Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into())),
+            Err(err) => stream_once(Err(err)),
+        }))
+    }
+
+    pub(crate) async fn rule_delete(&self, label: String) -> Result {
+        match self.rule_single(RuleRequest::Delete { label }).await? {
+            RuleResponse::Delete => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    pub(crate) async fn rule_set_label(&self, current_label: String, new_label: String) -> Result {
+        match self.rule_single(RuleRequest::SetLabel { current_label, new_label }).await? {
+            RuleResponse::SetLabel => Ok(()),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
     async fn single(&self, req: TransactionRequest) -> Result<TransactionResponse> {
         self.transaction_transmitter.single(req).await
     }
 
+    #[cfg(feature = "tracing")]
     async fn query_single(&self, req: QueryRequest) -> Result<QueryResponse> {
+        let span = query_span(&self.span, &req);
+        let response = self.query_single_untraced(req).instrument(span.clone()).await?;
+        record_answer_count(&span, &response);
+        Ok(response)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    async fn query_single(&self, req: QueryRequest) -> Result<QueryResponse> {
+        self.query_single_untraced(req).await
+    }
+
+    async fn query_single_untraced(&self, req: QueryRequest) -> Result<QueryResponse> {
         match self.single(TransactionRequest::Query(req)).await? {
             TransactionResponse::Query(query) => Ok(query),
             other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
         }
     }
 
+    async fn concept_single(&self, req: ConceptRequest) -> Result<ConceptResponse> {
+        match self.single(TransactionRequest::Concept(req)).await? {
+            TransactionResponse::Concept(res) => Ok(res),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    async fn thing_type_single(&self, req: ThingTypeRequest) -> Result<ThingTypeResponse> {
+        match self.single(TransactionRequest::ThingType(req)).await? {
+            TransactionResponse::ThingType(res) => Ok(res),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    async fn thing_single(&self, req: ThingRequest) -> Result<ThingResponse> {
+        match self.single(TransactionRequest::Thing(req)).await? {
+            TransactionResponse::Thing(res) => Ok(res),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    async fn logic_single(&self, req: LogicRequest) -> Result<LogicResponse> {
+        match self.single(TransactionRequest::Logic(req)).await? {
+            TransactionResponse::Logic(res) => Ok(res),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
+    async fn rule_single(&self, req: RuleRequest) -> Result<RuleResponse> {
+        match self.single(TransactionRequest::Rule(req)).await? {
+            TransactionResponse::Rule(res) => Ok(res),
+            other => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+        }
+    }
+
     fn stream(&self, req: TransactionRequest) -> Result<impl Stream<Item = Result<TransactionResponse>>> {
         self.transaction_transmitter.stream(req)
     }
 
-    fn query_stream(&self, req: QueryRequest) -> Result<impl Stream<Item = Result<QueryResponse>>> {
-        Ok(self.stream(TransactionRequest::Query(req))?.map(|response| match response {
-            Ok(TransactionResponse::Query(query)) => Ok(query),
+    #[cfg(feature = "tracing")]
+    fn query_stream(
+        &self,
+        req: QueryRequest,
+        handle: Option<&QueryHandle>,
+    ) -> Result<impl Stream<Item = Result<QueryResponse>>> {
+        let span = query_span(&self.span, &req);
+        let stream = span.in_scope(|| self.query_stream_untraced(req, handle))?;
+        Ok(count_answers(span, stream))
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn query_stream(
+        &self,
+        req: QueryRequest,
+        handle: Option<&QueryHandle>,
+    ) -> Result<impl Stream<Item = Result<QueryResponse>>> {
+        self.query_stream_untraced(req, handle)
+    }
+
+    fn query_stream_untraced(
+        &self,
+        req: QueryRequest,
+        handle: Option<&QueryHandle>,
+    ) -> Result<impl Stream<Item = Result<QueryResponse>>> {
+        let stream = self
+            .transaction_transmitter
+            .cancellable_stream(TransactionRequest::Query(req), handle.map(|handle| handle.cancelled.clone()))?;
+        Ok(stream.map(|response| match response {
+            Ok(TransactionResponse::Query(query)) => {
+                metrics::answers_received(query.answer_count());
+                Ok(query)
+            }
+            Ok(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            Err(err) => Err(err),
+        }))
+    }
+
+    fn concept_stream(&self, req: ConceptRequest) -> Result<impl Stream<Item = Result<ConceptResponse>>> {
+        Ok(self.stream(TransactionRequest::Concept(req))?.map(|response| match response {
+            Ok(TransactionResponse::Concept(res)) => Ok(res),
+            Ok(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            Err(err) => Err(err),
+        }))
+    }
+
+    fn thing_type_stream(&self, req: ThingTypeRequest) -> Result<impl Stream<Item = Result<ThingTypeResponse>>> {
+        Ok(self.stream(TransactionRequest::ThingType(req))?.map(|response| match response {
+            Ok(TransactionResponse::ThingType(res)) => Ok(res),
+            Ok(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            Err(err) => Err(err),
+        }))
+    }
+
+    fn thing_stream(&self, req: ThingRequest) -> Result<impl Stream<Item = Result<ThingResponse>>> {
+        Ok(self.stream(TransactionRequest::Thing(req))?.map(|response| match response {
+            Ok(TransactionResponse::Thing(res)) => Ok(res),
+            Ok(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
+            Err(err) => Err(err),
+        }))
+    }
+
+    fn logic_stream(&self, req: LogicRequest) -> Result<impl Stream<Item = Result<LogicResponse>>> {
+        Ok(self.stream(TransactionRequest::Logic(req))?.map(|response| match response {
+            Ok(TransactionResponse::Logic(res)) => Ok(res),
             Ok(other) => Err(InternalError::UnexpectedResponseType(format!("{other:?}")).into()),
             Err(err) => Err(err),
         }))
@@ -148,6 +609,29 @@ impl fmt::Debug for TransactionStream {
     }
 }
 
+/// Stops a streamed read query early. Once cancelled, the client no longer fetches further answers
+/// and the corresponding stream ends. This does not abort the query on the server, which keeps its
+/// iterator open until the transaction closes. Answers of insert and update queries are always
+/// fetched in full, so that every write is applied before the transaction commits.
+#[derive(Clone, Debug)]
+pub struct QueryHandle {
+    cancelled: Arc<AtomicCell<bool>>,
+}
+
+impl QueryHandle {
+    fn new() -> Self {
+        Self { cancelled: Arc::new(AtomicCell::new(false)) }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load()
+    }
+}
+
 fn stream_once<'a, T: Send + 'a>(value: T) -> stream::BoxStream<'a, T> {
     stream_iter(iter::once(value))
 }
@@ -0,0 +1,82 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::sync::Arc;
+
+use futures::Stream;
+
+use crate::{
+    common::Result,
+    concept::{attribute::ValueType, AttributeType, EntityType, RelationType, SchemaException, Thing, ThingType},
+    connection::TransactionStream,
+};
+
+#[derive(Debug)]
+pub struct ConceptManager {
+    transaction_stream: Arc<TransactionStream>,
+}
+
+impl ConceptManager {
+    pub(super) fn new(transaction_stream: Arc<TransactionStream>) -> ConceptManager {
+        ConceptManager { transaction_stream }
+    }
+
+    pub async fn get_entity_type(&self, label: &str) -> Result<Option<EntityType>> {
+        match self.transaction_stream.get_thing_type(label.to_string()).await? {
+            Some(ThingType::Entity(entity_type)) => Ok(Some(entity_type)),
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn get_relation_type(&self, label: &str) -> Result<Option<RelationType>> {
+        match self.transaction_stream.get_thing_type(label.to_string()).await? {
+            Some(ThingType::Relation(relation_type)) => Ok(Some(relation_type)),
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn get_attribute_type(&self, label: &str) -> Result<Option<AttributeType>> {
+        match self.transaction_stream.get_thing_type(label.to_string()).await? {
+            Some(ThingType::Attribute(attribute_type)) => Ok(Some(attribute_type)),
+            _ => Ok(None),
+        }
+    }
+
+    pub async fn put_entity_type(&self, label: &str) -> Result<EntityType> {
+        self.transaction_stream.put_entity_type(label.to_string()).await
+    }
+
+    pub async fn put_relation_type(&self, label: &str) -> Result<RelationType> {
+        self.transaction_stream.put_relation_type(label.to_string()).await
+    }
+
+    pub async fn put_attribute_type(&self, label: &str, value_type: ValueType) -> Result<AttributeType> {
+        self.transaction_stream.put_attribute_type(label.to_string(), value_type).await
+    }
+
+    pub async fn get_thing(&self, iid: &[u8]) -> Result<Option<Thing>> {
+        self.transaction_stream.get_thing(iid.to_vec()).await
+    }
+
+    pub fn get_schema_exceptions(&self) -> Result<impl Stream<Item = Result<SchemaException>>> {
+        self.transaction_stream.get_schema_exceptions()
+    }
+}
@@ -19,7 +19,14 @@
  * under the License.
  */
 
-use std::{fmt, future::Future, sync::RwLock, thread::sleep, time::Duration};
+use std::{
+    fmt,
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        RwLock,
+    },
+};
 
 use itertools::Itertools;
 use log::{debug, error};
@@ -29,7 +36,7 @@ use crate::{
         address::Address,
         error::ConnectionError,
         info::{DatabaseInfo, ReplicaInfo},
-        Error, Result,
+        metrics, Error, Result,
     },
     connection::ServerConnection,
     Connection,
@@ -38,24 +45,22 @@ use crate::{
 pub struct Database {
     name: String,
     replicas: RwLock<Vec<Replica>>,
+    read_replica_offset: AtomicUsize,
     connection: Connection,
 }
 
 impl Database {
-    const PRIMARY_REPLICA_TASK_MAX_RETRIES: usize = 10;
-    const FETCH_REPLICAS_MAX_RETRIES: usize = 10;
-    const WAIT_FOR_PRIMARY_REPLICA_SELECTION: Duration = Duration::from_secs(2);
-
-    pub(super) fn new(database_info: DatabaseInfo, connection: Connection) -> Result<Self> {
+    pub(super) async fn new(database_info: DatabaseInfo, connection: Connection) -> Result<Self> {
         let name = database_info.name.clone();
-        let replicas = RwLock::new(Replica::try_from_info(database_info, &connection)?);
-        Ok(Self { name, replicas, connection })
+        let replicas = RwLock::new(Replica::try_from_info(database_info, &connection).await?);
+        Ok(Self { name, replicas, read_replica_offset: AtomicUsize::new(0), connection })
     }
 
     pub(super) async fn get(name: String, connection: Connection) -> Result<Self> {
         Ok(Self {
             name: name.to_string(),
             replicas: RwLock::new(Replica::fetch_all(name, connection.clone()).await?),
+            read_replica_offset: AtomicUsize::new(0),
             connection,
         })
     }
@@ -84,33 +89,57 @@ impl Database {
         self.run_failsafe(|database, _, _| async move { database.rule_schema().await }).await
     }
 
+    pub(crate) async fn run_on_primary_server<F, P, R>(&self, task: F) -> Result<R>
+    where
+        F: Fn(ServerConnection) -> P,
+        P: Future<Output = Result<R>>,
+    {
+        self.run_on_primary_replica(|database, _, _| task(database.connection().clone())).await
+    }
+
     pub(super) async fn run_failsafe<F, P, R>(&self, task: F) -> Result<R>
     where
         F: Fn(ServerDatabase, ServerConnection, bool) -> P,
         P: Future<Output = Result<R>>,
     {
-        match self.run_on_any_replica(&task).await {
+        let replicas = self.replicas.read().unwrap().clone();
+        self.run_failsafe_on(replicas, task).await
+    }
+
+    pub(super) async fn run_read_failsafe<F, P, R>(&self, task: F) -> Result<R>
+    where
+        F: Fn(ServerDatabase, ServerConnection, bool) -> P,
+        P: Future<Output = Result<R>>,
+    {
+        self.run_failsafe_on(self.read_replicas(), task).await
+    }
+
+    async fn run_failsafe_on<F, P, R>(&self, replicas: Vec<Replica>, task: F) -> Result<R>
+    where
+        F: Fn(ServerDatabase, ServerConnection, bool) -> P,
+        P: Future<Output = Result<R>>,
+    {
+        match self.run_on_any_replica(replicas, &task).await {
             Err(Error::Connection(ConnectionError::ClusterReplicaNotPrimary())) => {
                 debug!("Attempted to run on a non-primary replica, retrying on primary...");
+                metrics::failover("replica_not_primary");
                 self.run_on_primary_replica(&task).await
             }
             res => res,
         }
     }
 
-    async fn run_on_any_replica<F, P, R>(&self, task: F) -> Result<R>
+    async fn run_on_any_replica<F, P, R>(&self, replicas: Vec<Replica>, task: F) -> Result<R>
     where
         F: Fn(ServerDatabase, ServerConnection, bool) -> P,
         P: Future<Output = Result<R>>,
     {
         let mut is_first_run = true;
-        let replicas = self.replicas.read().unwrap().clone();
         for replica in replicas {
-            match task(replica.database.clone(), self.connection.connection(&replica.address)?.clone(), is_first_run)
-                .await
-            {
+            match task(replica.database.clone(), self.connection.connection(&replica.address)?, is_first_run).await {
                 Err(Error::Connection(ConnectionError::UnableToConnect())) => {
                     debug!("Unable to connect to {}. Attempting next server.", replica.address);
+                    metrics::failover("unable_to_connect");
                 }
                 res => return res,
             }
@@ -119,7 +148,7 @@ impl Database {
         Err(self.connection.unable_to_connect_error())
     }
 
-    async fn run_on_primary_replica<F, P, R>(&self, task: F) -> Result<R>
+    pub(super) async fn run_on_primary_replica<F, P, R>(&self, task: F) -> Result<R>
     where
         F: Fn(ServerDatabase, ServerConnection, bool) -> P,
         P: Future<Output = Result<R>>,
@@ -127,10 +156,10 @@ impl Database {
         let mut primary_replica =
             if let Some(replica) = self.primary_replica() { replica } else { self.seek_primary_replica().await? };
 
-        for retry in 0..Self::PRIMARY_REPLICA_TASK_MAX_RETRIES {
+        for retry in 0..self.connection.settings().primary_replica_task_max_retries {
             match task(
                 primary_replica.database.clone(),
-                self.connection.connection(&primary_replica.address)?.clone(),
+                self.connection.connection(&primary_replica.address)?,
                 retry == 0,
             )
             .await
@@ -139,7 +168,8 @@ impl Database {
                     ConnectionError::ClusterReplicaNotPrimary() | ConnectionError::UnableToConnect(),
                 )) => {
                     debug!("Primary replica error, waiting...");
-                    Self::wait_for_primary_replica_selection().await;
+                    metrics::failover("primary_replica_error");
+                    self.wait_for_primary_replica_selection().await;
                     primary_replica = self.seek_primary_replica().await?;
                 }
                 res => return res,
@@ -149,24 +179,36 @@ impl Database {
     }
 
     async fn seek_primary_replica(&self) -> Result<Replica> {
-        for _ in 0..Self::FETCH_REPLICAS_MAX_RETRIES {
+        for _ in 0..self.connection.settings().fetch_replicas_max_retries {
             let replicas = Replica::fetch_all(self.name.clone(), self.connection.clone()).await?;
             *self.replicas.write().unwrap() = replicas;
             if let Some(replica) = self.primary_replica() {
                 return Ok(replica);
             }
-            Self::wait_for_primary_replica_selection().await;
+            self.wait_for_primary_replica_selection().await;
         }
         Err(self.connection.unable_to_connect_error())
     }
 
+    fn read_replicas(&self) -> Vec<Replica> {
+        // The preferred replica goes first, then the secondaries in rotating order so that reads are spread
+        // across them, and the primary is only used as a last resort.
+        let replicas = self.replicas.read().unwrap().clone();
+        let (preferred, others): (Vec<_>, Vec<_>) = replicas.into_iter().partition(|replica| replica.is_preferred);
+        let (primary, mut secondaries): (Vec<_>, Vec<_>) = others.into_iter().partition(|replica| replica.is_primary);
+        if !secondaries.is_empty() {
+            let offset = self.read_replica_offset.fetch_add(1, Ordering::Relaxed) % secondaries.len();
+            secondaries.rotate_left(offset);
+        }
+        preferred.into_iter().chain(secondaries).chain(primary).collect()
+    }
+
     fn primary_replica(&self) -> Option<Replica> {
         self.replicas.read().unwrap().iter().filter(|r| r.is_primary).max_by_key(|r| r.term).cloned()
     }
 
-    async fn wait_for_primary_replica_selection() {
-        // FIXME: blocking sleep! Can't do agnostic async sleep.
-        sleep(Self::WAIT_FOR_PRIMARY_REPLICA_SELECTION);
+    async fn wait_for_primary_replica_selection(&self) {
+        self.connection.sleep(self.connection.settings().primary_replica_selection_wait).await;
     }
 }
 
@@ -198,12 +240,16 @@ impl Replica {
         }
     }
 
-    fn try_from_info(database_info: DatabaseInfo, connection: &Connection) -> Result<Vec<Self>> {
+    async fn try_from_info(database_info: DatabaseInfo, connection: &Connection) -> Result<Vec<Self>> {
+        if database_info.replicas.iter().any(|replica| connection.connection(&replica.address).is_err()) {
+            debug!("Replica info mentions an unknown server, refreshing cluster servers...");
+            connection.refresh_servers_async().await?;
+        }
         database_info
             .replicas
             .into_iter()
             .map(|replica| {
-                let server_connection = connection.connection(&replica.address)?.clone();
+                let server_connection = connection.connection(&replica.address)?;
                 Ok(Replica::new(database_info.name.clone(), replica, server_connection))
             })
             .try_collect()
@@ -214,7 +260,7 @@ impl Replica {
             let res = server_connection.get_database_replicas(name.clone()).await;
             match res {
                 Ok(res) => {
-                    return Replica::try_from_info(res, &connection);
+                    return Replica::try_from_info(res, &connection).await;
                 }
                 Err(Error::Connection(ConnectionError::UnableToConnect())) => {
                     error!(
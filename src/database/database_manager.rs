@@ -61,7 +61,11 @@ impl DatabaseManager {
         for server_connection in self.connection.connections() {
             match server_connection.all_databases().await {
                 Ok(list) => {
-                    return list.into_iter().map(|db_info| Database::new(db_info, self.connection.clone())).collect()
+                    let mut databases = Vec::with_capacity(list.len());
+                    for database_info in list {
+                        databases.push(Database::new(database_info, self.connection.clone()).await?);
+                    }
+                    return Ok(databases);
                 }
                 Err(err) => error_buffer.push(format!("- {}: {}", server_connection.address(), err)),
             }
@@ -19,10 +19,13 @@
  * under the License.
  */
 
+mod concept;
 mod database;
 mod database_manager;
+mod logic;
 mod query;
 mod session;
 mod transaction;
 
+pub(crate) use self::query::QueryManager;
 pub use self::{database::Database, database_manager::DatabaseManager, session::Session, transaction::Transaction};
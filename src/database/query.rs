@@ -21,13 +21,14 @@
 
 use std::sync::Arc;
 
-use futures::Stream;
+use futures::{Stream, StreamExt};
 
 use crate::{
-    answer::{ConceptMap, Numeric},
+    answer::{ConceptMap, ConceptMapGroup, Explainable, FromConceptMap, Numeric, NumericGroup},
     common::Result,
     connection::TransactionStream,
-    Options,
+    logic::Explanation,
+    Options, QueryHandle,
 };
 
 #[derive(Debug)]
@@ -72,6 +73,30 @@ impl QueryManager {
         self.transaction_stream.match_(query.to_string(), options)
     }
 
+    pub fn match_cancellable(&self, query: &str) -> Result<(impl Stream<Item = Result<ConceptMap>>, QueryHandle)> {
+        self.match_cancellable_with_options(query, Options::new())
+    }
+
+    pub fn match_cancellable_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<(impl Stream<Item = Result<ConceptMap>>, QueryHandle)> {
+        self.transaction_stream.match_cancellable(query.to_string(), options)
+    }
+
+    pub fn match_as<T: FromConceptMap>(&self, query: &str) -> Result<impl Stream<Item = Result<T>>> {
+        self.match_as_with_options(query, Options::new())
+    }
+
+    pub fn match_as_with_options<T: FromConceptMap>(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<T>>> {
+        Ok(self.match_with_options(query, options)?.map(|result| result.and_then(T::from_concept_map)))
+    }
+
     pub fn insert(&self, query: &str) -> Result<impl Stream<Item = Result<ConceptMap>>> {
         self.insert_with_options(query, Options::new())
     }
@@ -95,4 +120,40 @@ impl QueryManager {
     pub async fn match_aggregate_with_options(&self, query: &str, options: Options) -> Result<Numeric> {
         self.transaction_stream.match_aggregate(query.to_string(), options).await
     }
+
+    pub fn match_group(&self, query: &str) -> Result<impl Stream<Item = Result<ConceptMapGroup>>> {
+        self.match_group_with_options(query, Options::new())
+    }
+
+    pub fn match_group_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<ConceptMapGroup>>> {
+        self.transaction_stream.match_group(query.to_string(), options)
+    }
+
+    pub fn match_group_aggregate(&self, query: &str) -> Result<impl Stream<Item = Result<NumericGroup>>> {
+        self.match_group_aggregate_with_options(query, Options::new())
+    }
+
+    pub fn match_group_aggregate_with_options(
+        &self,
+        query: &str,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<NumericGroup>>> {
+        self.transaction_stream.match_group_aggregate(query.to_string(), options)
+    }
+
+    pub fn explain(&self, explainable: &Explainable) -> Result<impl Stream<Item = Result<Explanation>>> {
+        self.explain_with_options(explainable, Options::new())
+    }
+
+    pub fn explain_with_options(
+        &self,
+        explainable: &Explainable,
+        options: Options,
+    ) -> Result<impl Stream<Item = Result<Explanation>>> {
+        self.transaction_stream.explain(explainable, options)
+    }
 }
@@ -19,22 +19,59 @@
  * under the License.
  */
 
-use std::sync::RwLock;
+use std::{
+    fmt, mem,
+    sync::{Arc, Mutex, RwLock},
+    time::Duration,
+};
 
-use crossbeam::atomic::AtomicCell;
-use log::warn;
+use futures::{future::BoxFuture, lock::Mutex as AsyncMutex};
+use log::{debug, warn};
+#[cfg(feature = "tracing")]
+use tracing::Instrument;
 
+use super::database::ServerDatabase;
+#[cfg(feature = "tracing")]
+use crate::connection::trace::transaction_span;
 use crate::{
-    common::{error::ConnectionError, info::SessionInfo, Result, SessionType, TransactionType},
-    Database, Options, Transaction,
+    common::{error::ConnectionError, info::SessionInfo, metrics, Result, SessionType, TransactionType},
+    Database, Error, Options, Transaction,
 };
 
-#[derive(Debug)]
+type CloseCallback = Box<dyn FnOnce(Option<Error>) + Send>;
+
+enum CloseState {
+    Open(Vec<CloseCallback>),
+    Closed(Option<Error>),
+}
+
+impl CloseState {
+    fn close(state: &Mutex<CloseState>, close_reason: Option<Error>) -> bool {
+        let mut state = state.lock().unwrap();
+        let callbacks = match &mut *state {
+            CloseState::Open(callbacks) => mem::take(callbacks),
+            CloseState::Closed(_) => return false,
+        };
+        *state = CloseState::Closed(close_reason.clone());
+        drop(state);
+        for callback in callbacks {
+            callback(close_reason.clone());
+        }
+        true
+    }
+}
+
 pub struct Session {
     database: Database,
-    server_session_info: RwLock<SessionInfo>,
+    // Read transactions that may run on any replica use their own server session, so that moving write
+    // transactions to the primary replica never closes a session that reads are still using.
+    primary_session_info: RwLock<Option<SessionInfo>>,
+    replica_session_info: RwLock<Option<SessionInfo>>,
+    // Serialises replacing a server session, so that concurrent transactions failing over together share one.
+    reopen_lock: AsyncMutex<()>,
     session_type: SessionType,
-    is_open: AtomicCell<bool>,
+    options: Options,
+    close_state: Arc<Mutex<CloseState>>,
 }
 
 impl Drop for Session {
@@ -46,19 +83,40 @@ impl Drop for Session {
 }
 
 impl Session {
+    const TRANSACTION_RETRY_BASE_BACKOFF: Duration = Duration::from_millis(100);
+    const TRANSACTION_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(5);
+
     pub async fn new(database: Database, session_type: SessionType) -> Result<Self> {
-        let server_session_info = RwLock::new(
-            database
-                .run_failsafe(|database, _, _| async move {
-                    database
-                        .connection()
-                        .open_session(database.name().to_owned(), session_type, Options::default())
-                        .await
-                })
-                .await?,
-        );
+        Self::new_with_options(database, session_type, Options::new()).await
+    }
+
+    pub async fn new_with_options(database: Database, session_type: SessionType, options: Options) -> Result<Self> {
+        let open_session = |database: ServerDatabase, _, _| {
+            let options = options.clone();
+            async move { database.connection().open_session(database.name().to_owned(), session_type, options).await }
+        };
+        let read_any_replica = session_type == SessionType::Data && options.read_any_replica.unwrap_or(false);
+        let (primary_session_info, replica_session_info) = if read_any_replica {
+            (None, Some(database.run_read_failsafe(open_session).await?))
+        } else {
+            (Some(database.run_on_primary_replica(open_session).await?), None)
+        };
 
-        Ok(Self { database, session_type, server_session_info, is_open: AtomicCell::new(true) })
+        let session = Self {
+            database,
+            session_type,
+            options,
+            primary_session_info: RwLock::new(primary_session_info),
+            replica_session_info: RwLock::new(replica_session_info),
+            reopen_lock: AsyncMutex::new(()),
+            close_state: Arc::new(Mutex::new(CloseState::Open(Vec::new()))),
+        };
+        for session_info in [&session.primary_session_info, &session.replica_session_info] {
+            if let Some(session_info) = &*session_info.read().unwrap() {
+                session.watch_server_session(session_info);
+            }
+        }
+        Ok(session)
     }
 
     pub fn database_name(&self) -> &str {
@@ -70,18 +128,45 @@ impl Session {
     }
 
     pub fn is_open(&self) -> bool {
-        self.is_open.load()
+        matches!(*self.close_state.lock().unwrap(), CloseState::Open(_))
+    }
+
+    pub fn on_close(&self, callback: impl FnOnce(Option<Error>) + Send + 'static) {
+        let close_reason = match &mut *self.close_state.lock().unwrap() {
+            CloseState::Open(callbacks) => {
+                callbacks.push(Box::new(callback));
+                return;
+            }
+            CloseState::Closed(close_reason) => close_reason.clone(),
+        };
+        callback(close_reason);
     }
 
     pub fn force_close(&self) -> Result {
-        if self.is_open.compare_exchange(true, false).is_ok() {
-            let session_info = self.server_session_info.write().unwrap();
-            let connection = self.database.connection().connection(&session_info.address).unwrap();
-            connection.close_session(session_info.session_id.clone())?;
+        if CloseState::close(&self.close_state, None) {
+            let mut result = Ok(());
+            for session_info in [&self.primary_session_info, &self.replica_session_info] {
+                if let Some(session_info) = session_info.write().unwrap().take() {
+                    // The server may have left the cluster since the session was opened.
+                    if let Ok(connection) = self.database.connection().connection(&session_info.address) {
+                        result = result.and(connection.close_session(session_info.session_id));
+                    }
+                }
+            }
+            result?;
         }
         Ok(())
     }
 
+    fn watch_server_session(&self, session_info: &SessionInfo) {
+        if let Ok(connection) = self.database.connection().connection(&session_info.address) {
+            let close_state = self.close_state.clone();
+            connection.on_session_close(&session_info.session_id, move |close_reason| {
+                CloseState::close(&close_state, Some(close_reason));
+            });
+        }
+    }
+
     pub async fn transaction(&self, transaction_type: TransactionType) -> Result<Transaction> {
         self.transaction_with_options(transaction_type, Options::new()).await
     }
@@ -91,39 +176,145 @@ impl Session {
         transaction_type: TransactionType,
         options: Options,
     ) -> Result<Transaction> {
+        let transaction = self.open_transaction(transaction_type, options);
+        #[cfg(feature = "tracing")]
+        let transaction = transaction.instrument(transaction_span(self.database.name(), transaction_type));
+        transaction.await
+    }
+
+    async fn open_transaction(&self, transaction_type: TransactionType, options: Options) -> Result<Transaction> {
         if !self.is_open() {
             return Err(ConnectionError::SessionIsClosed().into());
         }
 
-        let (session_info, transaction_stream) = self
-            .database
-            .run_failsafe(|database, _, is_first_run| {
-                let session_info = self.server_session_info.read().unwrap().clone();
-                let session_type = self.session_type;
-                let options = options.clone();
-                async move {
-                    let connection = database.connection();
-                    let session_info = if is_first_run {
-                        session_info
-                    } else {
-                        connection.open_session(database.name().to_owned(), session_type, options.clone()).await?
-                    };
-                    Ok((
-                        session_info.clone(),
-                        connection
-                            .open_transaction(
-                                session_info.session_id,
-                                transaction_type,
-                                options,
-                                session_info.network_latency,
-                            )
-                            .await?,
-                    ))
+        let read_any_replica = self.session_type == SessionType::Data
+            && transaction_type == TransactionType::Read
+            && options.read_any_replica.or(self.options.read_any_replica).unwrap_or(false);
+        let server_session_info =
+            if read_any_replica { &self.replica_session_info } else { &self.primary_session_info };
+        let replica_session_info =
+            if read_any_replica { self.replica_session_info.read().unwrap().clone() } else { None };
+        if let Some(session_info) = replica_session_info {
+            // Reads stay on the replica their server session was opened on, unless it has become unreachable.
+            let connection = self.database.connection().connection(&session_info.address)?;
+            match connection
+                .open_transaction(
+                    session_info.session_id,
+                    transaction_type,
+                    options.clone(),
+                    session_info.network_latency,
+                )
+                .await
+            {
+                Err(Error::Connection(ConnectionError::UnableToConnect())) => {
+                    debug!("Unable to connect to {}. Reopening the session on another replica.", session_info.address);
+                    metrics::failover("unable_to_connect");
                 }
-            })
-            .await?;
+                res => return res.map(Transaction::new),
+            }
+        }
 
-        *self.server_session_info.write().unwrap() = session_info;
+        let open_transaction = |database: ServerDatabase, _, _| {
+            let session_info = server_session_info.read().unwrap().clone();
+            let options = options.clone();
+            async move {
+                let session_info = match session_info {
+                    Some(session_info) if &session_info.address == database.connection().address() => session_info,
+                    _ => self.reopen_session(server_session_info, &database, options.clone()).await?,
+                };
+                database
+                    .connection()
+                    .open_transaction(session_info.session_id, transaction_type, options, session_info.network_latency)
+                    .await
+            }
+        };
+        let transaction_stream = if read_any_replica {
+            self.database.run_read_failsafe(open_transaction).await?
+        } else {
+            self.database.run_on_primary_replica(open_transaction).await?
+        };
         Ok(Transaction::new(transaction_stream))
     }
+
+    async fn reopen_session(
+        &self,
+        server_session_info: &RwLock<Option<SessionInfo>>,
+        database: &ServerDatabase,
+        options: Options,
+    ) -> Result<SessionInfo> {
+        let _reopening = self.reopen_lock.lock().await;
+        // A concurrent transaction may already have moved the session to this server.
+        let current_session_info = server_session_info.read().unwrap().clone();
+        if let Some(session_info) = current_session_info {
+            if &session_info.address == database.connection().address() {
+                return Ok(session_info);
+            }
+        }
+
+        let session_info =
+            database.connection().open_session(database.name().to_owned(), self.session_type, options).await?;
+        let previous_session_info = server_session_info.write().unwrap().replace(session_info.clone());
+        self.watch_server_session(&session_info);
+        if let Some(previous_session_info) = previous_session_info {
+            if let Ok(connection) = self.database.connection().connection(&previous_session_info.address) {
+                connection.close_session(previous_session_info.session_id).ok();
+            }
+        }
+        Ok(session_info)
+    }
+
+    pub async fn run_in_transaction<F, T>(
+        &self,
+        transaction_type: TransactionType,
+        options: Options,
+        task: F,
+    ) -> Result<T>
+    where
+        F: for<'t> Fn(&'t Transaction<'_>) -> BoxFuture<'t, Result<T>>,
+    {
+        let retry_limit = self.database.connection().settings().transaction_retry_limit;
+        let mut retry = 0;
+        loop {
+            match self.try_run_in_transaction(transaction_type, options.clone(), &task).await {
+                Err(err) if err.is_transient() && retry < retry_limit => {
+                    debug!("Retrying transaction after transient error: {}", err);
+                    let factor = 2u32.checked_pow(retry as u32).unwrap_or(u32::MAX);
+                    let backoff = Self::TRANSACTION_RETRY_BASE_BACKOFF
+                        .saturating_mul(factor)
+                        .min(Self::TRANSACTION_RETRY_MAX_BACKOFF);
+                    self.database.connection().sleep(backoff).await;
+                    retry += 1;
+                }
+                res => return res,
+            }
+        }
+    }
+
+    async fn try_run_in_transaction<F, T>(
+        &self,
+        transaction_type: TransactionType,
+        options: Options,
+        task: &F,
+    ) -> Result<T>
+    where
+        F: for<'t> Fn(&'t Transaction<'_>) -> BoxFuture<'t, Result<T>>,
+    {
+        let transaction = self.transaction_with_options(transaction_type, options).await?;
+        let result = task(&transaction).await?;
+        transaction.commit().await?;
+        Ok(result)
+    }
+}
+
+impl fmt::Debug for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Session")
+            .field("database", &self.database)
+            .field("primary_session_info", &self.primary_session_info)
+            .field("replica_session_info", &self.replica_session_info)
+            .field("session_type", &self.session_type)
+            .field("options", &self.options)
+            .field("is_open", &self.is_open())
+            .finish()
+    }
 }
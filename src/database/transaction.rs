@@ -21,11 +21,11 @@
 
 use std::{fmt, marker::PhantomData, sync::Arc};
 
-use super::query::QueryManager;
+use super::{concept::ConceptManager, logic::LogicManager, query::QueryManager};
 use crate::{
     common::{Result, TransactionType},
     connection::TransactionStream,
-    Options,
+    Error, Options,
 };
 
 pub struct Transaction<'a> {
@@ -33,6 +33,8 @@ pub struct Transaction<'a> {
     options: Options,
 
     query: QueryManager,
+    concepts: ConceptManager,
+    logic: LogicManager,
     transaction_stream: Arc<TransactionStream>,
 
     _lifetime_guard: PhantomData<&'a ()>,
@@ -45,6 +47,8 @@ impl Transaction<'_> {
             type_: transaction_stream.type_(),
             options: transaction_stream.options().clone(),
             query: QueryManager::new(transaction_stream.clone()),
+            concepts: ConceptManager::new(transaction_stream.clone()),
+            logic: LogicManager::new(transaction_stream.clone()),
             transaction_stream,
             _lifetime_guard: PhantomData::default(),
         }
@@ -62,6 +66,18 @@ impl Transaction<'_> {
         &self.query
     }
 
+    pub fn concepts(&self) -> &ConceptManager {
+        &self.concepts
+    }
+
+    pub fn logic(&self) -> &LogicManager {
+        &self.logic
+    }
+
+    pub(crate) fn transaction_stream(&self) -> &TransactionStream {
+        &self.transaction_stream
+    }
+
     pub async fn commit(self) -> Result {
         self.transaction_stream.commit().await
     }
@@ -69,6 +85,14 @@ impl Transaction<'_> {
     pub async fn rollback(&self) -> Result {
         self.transaction_stream.rollback().await
     }
+
+    pub async fn close(self) -> Result {
+        self.transaction_stream.close().await
+    }
+
+    pub fn on_close(&self, callback: impl FnOnce(Option<Error>) + Send + 'static) {
+        self.transaction_stream.on_close(callback)
+    }
 }
 
 impl fmt::Debug for Transaction<'_> {
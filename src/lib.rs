@@ -19,14 +19,22 @@
  * under the License.
  */
 
-mod answer;
+pub mod answer;
+pub mod blocking;
 mod common;
 pub mod concept;
 mod connection;
 mod database;
+pub mod logic;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod user;
 
 pub use self::{
-    common::{error, Credential, Error, Options, Result, SessionType, TransactionType},
-    connection::Connection,
+    common::{
+        error, ConnectionSettings, Credential, Error, Options, Result, ServerStatus, SessionType, TransactionType,
+    },
+    connection::{Connection, QueryHandle},
     database::{Database, DatabaseManager, Session, Transaction},
+    user::{User, UserManager},
 };
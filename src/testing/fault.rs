@@ -0,0 +1,94 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{common::address::Address, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fault {
+    Unavailable,
+    RstStream,
+}
+
+#[derive(Debug, Default)]
+struct FaultRules {
+    latency: Option<Duration>,
+    pending: VecDeque<(Option<Address>, Fault)>,
+    drop_transaction_streams_after: Option<usize>,
+    reorder_transaction_responses: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FaultPolicy {
+    rules: Arc<Mutex<FaultRules>>,
+}
+
+impl FaultPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_latency(&self, latency: Option<Duration>) {
+        self.rules.lock().unwrap().latency = latency;
+    }
+
+    pub fn fail_next(&self, fault: Fault, count: usize) {
+        self.rules.lock().unwrap().pending.extend((0..count).map(|_| (None, fault)));
+    }
+
+    pub fn fail_next_at(&self, address: &str, fault: Fault, count: usize) -> Result {
+        let address: Address = address.parse()?;
+        self.rules.lock().unwrap().pending.extend((0..count).map(|_| (Some(address.clone()), fault)));
+        Ok(())
+    }
+
+    pub fn drop_transaction_streams_after(&self, responses: Option<usize>) {
+        self.rules.lock().unwrap().drop_transaction_streams_after = responses;
+    }
+
+    pub fn reorder_transaction_responses(&self, enabled: bool) {
+        self.rules.lock().unwrap().reorder_transaction_responses = enabled;
+    }
+
+    pub fn reset(&self) {
+        *self.rules.lock().unwrap() = FaultRules::default();
+    }
+
+    pub(crate) fn latency(&self) -> Option<Duration> {
+        self.rules.lock().unwrap().latency
+    }
+
+    pub(crate) fn take_fault(&self, address: &Address) -> Option<Fault> {
+        let mut rules = self.rules.lock().unwrap();
+        let position = rules.pending.iter().position(|(target, _)| target.as_ref().map_or(true, |t| t == address))?;
+        rules.pending.remove(position).map(|(_, fault)| fault)
+    }
+
+    pub(crate) fn transaction_stream_faults(&self) -> (Option<usize>, bool) {
+        let rules = self.rules.lock().unwrap();
+        (rules.drop_transaction_streams_after, rules.reorder_transaction_responses)
+    }
+}
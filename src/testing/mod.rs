@@ -0,0 +1,220 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+mod fault;
+mod service;
+
+use std::{
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use futures::stream;
+use tokio::{
+    runtime,
+    sync::oneshot::{channel as oneshot_async, Sender as AsyncOneshotSender},
+};
+use tonic::{transport::Server, Request, Status};
+use typedb_protocol::{type_db_cluster_server::TypeDbClusterServer, type_db_server::TypeDbServer};
+
+pub use self::fault::{Fault, FaultPolicy};
+use self::service::{ClusterState, MockService, NodeState};
+use crate::{common::StdResult, Result};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MockRpc {
+    Any,
+    DatabaseManagement,
+    Session,
+    TransactionOpen,
+    Query,
+    Commit,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockFailure {
+    Unavailable,
+    ReplicaNotPrimary,
+    TokenCredentialInvalid,
+    CommitConflict,
+    Server { code: String, message: String },
+}
+
+impl MockFailure {
+    fn to_status(&self) -> Status {
+        match self {
+            MockFailure::Unavailable => Status::unavailable("The mock server is unavailable."),
+            MockFailure::ReplicaNotPrimary => Status::internal("[RPL01] The replica is not the primary replica."),
+            MockFailure::TokenCredentialInvalid => Status::unauthenticated("[CLS08] Invalid token credential."),
+            MockFailure::CommitConflict => Status::internal(
+                "[TXN13] Transaction isolation violation: a concurrent transaction modified the same data.",
+            ),
+            MockFailure::Server { code, message } => Status::internal(format!("[{code}] {message}")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MockResponse {
+    Empty,
+    Aggregate(i64),
+    /// Answers a `match` query with the given number of single-answer pages, waiting for a stream
+    /// continuation from the client before sending each page after the first.
+    Pages(usize),
+    Failure(MockFailure),
+}
+
+pub struct MockServer {
+    address: String,
+    node: Arc<Mutex<NodeState>>,
+    cluster: Arc<Mutex<ClusterState>>,
+    shutdown_sink: Option<AsyncOneshotSender<()>>,
+}
+
+impl MockServer {
+    pub fn start() -> Result<Self> {
+        let mut cluster = MockCluster::start(1)?;
+        Ok(cluster.servers.remove(0))
+    }
+
+    fn start_node(index: usize, listener: TcpListener, cluster: Arc<Mutex<ClusterState>>) -> Result<Self> {
+        let address = listener.local_addr()?.to_string();
+        let node = Arc::new(Mutex::new(NodeState::default()));
+        let service = MockService::new(index, node.clone(), cluster.clone());
+        let record_metadata = {
+            let node = node.clone();
+            move |request: Request<()>| -> StdResult<Request<()>, Status> {
+                node.lock().unwrap().record_metadata(request.metadata());
+                Ok(request)
+            }
+        };
+        let (shutdown_sink, shutdown_source) = oneshot_async::<()>();
+
+        listener.set_nonblocking(true)?;
+        let async_runtime = runtime::Builder::new_current_thread().enable_all().build()?;
+        thread::Builder::new().name(format!("mock server {address}")).spawn(move || {
+            async_runtime.block_on(async move {
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                let incoming = Box::pin(stream::unfold(listener, |listener| async move {
+                    let accepted = listener.accept().await.map(|(stream, _)| stream);
+                    Some((accepted, listener))
+                }));
+                Server::builder()
+                    .add_service(TypeDbServer::with_interceptor(service.clone(), record_metadata.clone()))
+                    .add_service(TypeDbClusterServer::with_interceptor(service, record_metadata))
+                    .serve_with_incoming_shutdown(incoming, async {
+                        shutdown_source.await.ok();
+                    })
+                    .await
+                    .ok();
+            })
+        })?;
+
+        Ok(Self { address, node, cluster, shutdown_sink: Some(shutdown_sink) })
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn fail_next(&self, rpc: MockRpc, failure: MockFailure, count: usize) {
+        self.node.lock().unwrap().push_failures(rpc, failure, count);
+    }
+
+    pub fn stream_continuations(&self) -> usize {
+        self.node.lock().unwrap().stream_continuations()
+    }
+
+    /// Values of the given gRPC metadata key, in the order the requests carrying it were received.
+    pub fn received_metadata(&self, key: &str) -> Vec<String> {
+        self.node.lock().unwrap().received_metadata(key)
+    }
+
+    pub fn on_query(&self, query: impl Into<String>, response: MockResponse) {
+        self.cluster.lock().unwrap().script(query.into(), response);
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown_sink) = self.shutdown_sink.take() {
+            shutdown_sink.send(()).ok();
+        }
+    }
+}
+
+pub struct MockCluster {
+    servers: Vec<MockServer>,
+    cluster: Arc<Mutex<ClusterState>>,
+}
+
+impl MockCluster {
+    pub fn start(size: usize) -> Result<Self> {
+        let listeners: Vec<TcpListener> =
+            (0..size).map(|_| TcpListener::bind("127.0.0.1:0")).collect::<std::io::Result<_>>()?;
+        let addresses =
+            listeners.iter().map(|listener| Ok(listener.local_addr()?.to_string())).collect::<Result<_>>()?;
+        let cluster = Arc::new(Mutex::new(ClusterState::new(addresses)));
+        let servers = listeners
+            .into_iter()
+            .enumerate()
+            .map(|(index, listener)| MockServer::start_node(index, listener, cluster.clone()))
+            .collect::<Result<_>>()?;
+        Ok(Self { servers, cluster })
+    }
+
+    pub fn addresses(&self) -> Vec<String> {
+        self.servers.iter().map(|server| server.address.clone()).collect()
+    }
+
+    pub fn server(&self, index: usize) -> &MockServer {
+        &self.servers[index]
+    }
+
+    pub fn primary(&self) -> usize {
+        self.cluster.lock().unwrap().primary()
+    }
+
+    pub fn elect_primary(&self, index: usize) {
+        assert!(index < self.servers.len(), "there is no server with index {index} in the mock cluster");
+        self.cluster.lock().unwrap().elect_primary(index);
+    }
+
+    /// Starts a new server and advertises it as a member of the cluster.
+    pub fn add_server(&mut self) -> Result<usize> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let index = self.cluster.lock().unwrap().join(listener.local_addr()?.to_string());
+        self.servers.push(MockServer::start_node(index, listener, self.cluster.clone())?);
+        Ok(index)
+    }
+
+    /// Stops advertising a server as a member of the cluster. The server itself keeps running.
+    pub fn remove_server(&self, index: usize) {
+        assert!(index < self.servers.len(), "there is no server with index {index} in the mock cluster");
+        assert_ne!(index, self.primary(), "the primary server cannot leave the mock cluster");
+        self.cluster.lock().unwrap().leave(index);
+    }
+
+    pub fn on_query(&self, query: impl Into<String>, response: MockResponse) {
+        self.cluster.lock().unwrap().script(query.into(), response);
+    }
+}
@@ -0,0 +1,627 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    sync::{Arc, Mutex},
+};
+
+use futures::StreamExt;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedSender};
+use tokio_stream::wrappers::UnboundedReceiverStream;
+use tonic::{
+    metadata::{KeyAndValueRef, MetadataMap},
+    Request, Response, Status, Streaming,
+};
+use typedb_protocol::{
+    cluster_database, cluster_database_manager, cluster_user, cluster_user_manager, core_database,
+    core_database_manager, numeric, query_manager, r#type, server_manager, session, transaction,
+    type_db_cluster_server, type_db_server, ClusterDatabase, ClusterUser, Concept, ConceptMap, Numeric, Server, Thing,
+    Type,
+};
+use uuid::Uuid;
+
+use super::{MockFailure, MockResponse, MockRpc};
+
+type ServiceResult<T> = Result<Response<T>, Status>;
+
+#[derive(Debug, Default)]
+pub(super) struct NodeState {
+    failures: VecDeque<(MockRpc, MockFailure)>,
+    stream_continuations: usize,
+    metadata: HashMap<String, Vec<String>>,
+}
+
+impl NodeState {
+    pub(super) fn push_failures(&mut self, rpc: MockRpc, failure: MockFailure, count: usize) {
+        self.failures.extend((0..count).map(|_| (rpc, failure.clone())));
+    }
+
+    fn take_failure(&mut self, rpc: MockRpc) -> Option<MockFailure> {
+        let position = self.failures.iter().position(|(target, _)| *target == MockRpc::Any || *target == rpc)?;
+        self.failures.remove(position).map(|(_, failure)| failure)
+    }
+
+    pub(super) fn stream_continuations(&self) -> usize {
+        self.stream_continuations
+    }
+
+    pub(super) fn record_metadata(&mut self, metadata: &MetadataMap) {
+        for entry in metadata.iter() {
+            if let KeyAndValueRef::Ascii(key, value) = entry {
+                if let Ok(value) = value.to_str() {
+                    self.metadata.entry(key.as_str().to_owned()).or_default().push(value.to_owned());
+                }
+            }
+        }
+    }
+
+    pub(super) fn received_metadata(&self, key: &str) -> Vec<String> {
+        self.metadata.get(key).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Debug)]
+pub(super) struct ClusterState {
+    addresses: Vec<String>,
+    departed: HashSet<usize>,
+    primary: usize,
+    term: i64,
+    databases: BTreeMap<String, String>,
+    users: BTreeMap<String, String>,
+    sessions: HashMap<Vec<u8>, String>,
+    scripted: HashMap<String, MockResponse>,
+}
+
+impl ClusterState {
+    pub(super) fn new(addresses: Vec<String>) -> Self {
+        Self {
+            addresses,
+            departed: HashSet::new(),
+            primary: 0,
+            term: 1,
+            databases: BTreeMap::new(),
+            users: BTreeMap::from([(String::from("admin"), String::from("password"))]),
+            sessions: HashMap::new(),
+            scripted: HashMap::new(),
+        }
+    }
+
+    pub(super) fn primary(&self) -> usize {
+        self.primary
+    }
+
+    pub(super) fn elect_primary(&mut self, index: usize) {
+        if self.primary != index {
+            self.primary = index;
+            self.term += 1;
+        }
+    }
+
+    pub(super) fn join(&mut self, address: String) -> usize {
+        self.addresses.push(address);
+        self.addresses.len() - 1
+    }
+
+    pub(super) fn leave(&mut self, index: usize) {
+        self.departed.insert(index);
+    }
+
+    fn members(&self) -> impl Iterator<Item = (usize, &String)> {
+        self.addresses.iter().enumerate().filter(|(index, _)| !self.departed.contains(index))
+    }
+
+    pub(super) fn script(&mut self, query: String, response: MockResponse) {
+        self.scripted.insert(query, response);
+    }
+
+    fn cluster_database(&self, name: String, index: usize) -> ClusterDatabase {
+        let replicas = self
+            .members()
+            .map(|(replica_index, address)| cluster_database::Replica {
+                address: address.clone(),
+                primary: replica_index == self.primary,
+                preferred: replica_index == index,
+                term: self.term,
+            })
+            .collect();
+        ClusterDatabase { name, replicas }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum QueryKind {
+    Single,
+    Aggregate,
+    Stream(StreamKind),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StreamKind {
+    Match,
+    Insert,
+    Update,
+}
+
+#[derive(Debug, Default)]
+struct TransactionState {
+    session_id: Option<Vec<u8>>,
+    database: Option<String>,
+    remaining_pages: HashMap<Vec<u8>, (usize, StreamKind)>,
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct MockService {
+    index: usize,
+    node: Arc<Mutex<NodeState>>,
+    cluster: Arc<Mutex<ClusterState>>,
+}
+
+impl MockService {
+    pub(super) fn new(index: usize, node: Arc<Mutex<NodeState>>, cluster: Arc<Mutex<ClusterState>>) -> Self {
+        Self { index, node, cluster }
+    }
+
+    fn check(&self, rpc: MockRpc) -> Result<(), Status> {
+        match self.node.lock().unwrap().take_failure(rpc) {
+            Some(failure) => Err(failure.to_status()),
+            None => Ok(()),
+        }
+    }
+
+    fn check_primary(&self) -> Result<(), Status> {
+        if self.cluster.lock().unwrap().primary == self.index {
+            Ok(())
+        } else {
+            Err(MockFailure::ReplicaNotPrimary.to_status())
+        }
+    }
+
+    fn database_schema(&self, name: &str) -> Result<String, Status> {
+        self.cluster.lock().unwrap().databases.get(name).cloned().ok_or_else(|| database_not_found(name))
+    }
+
+    async fn run_transaction(
+        self,
+        mut requests: Streaming<transaction::Client>,
+        mut state: TransactionState,
+        sink: UnboundedSender<Result<transaction::Server, Status>>,
+    ) {
+        while let Some(Ok(client)) = requests.next().await {
+            for req in client.reqs {
+                match self.handle_transaction_req(req, &mut state) {
+                    Ok((responses, is_closing)) => {
+                        responses.into_iter().for_each(|res| {
+                            sink.send(Ok(res)).ok();
+                        });
+                        if is_closing {
+                            return;
+                        }
+                    }
+                    Err(status) => {
+                        sink.send(Err(status)).ok();
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    fn handle_transaction_req(
+        &self,
+        req: transaction::Req,
+        state: &mut TransactionState,
+    ) -> Result<(Vec<transaction::Server>, bool), Status> {
+        let req_id = req.req_id;
+        if let Some(session_id) = &state.session_id {
+            // Closing a session closes its transactions.
+            if !self.cluster.lock().unwrap().sessions.contains_key(session_id) {
+                return Err(session_not_found());
+            }
+        }
+        match req.req {
+            Some(transaction::req::Req::OpenReq(open_req)) => {
+                self.check(MockRpc::TransactionOpen)?;
+                let session_database = (self.cluster.lock().unwrap().sessions.get(&open_req.session_id).cloned())
+                    .ok_or_else(session_not_found)?;
+                if open_req.r#type == transaction::Type::Write as i32 {
+                    self.check_primary()?;
+                }
+                state.session_id = Some(open_req.session_id);
+                state.database = Some(session_database);
+                Ok((vec![single_res(req_id, transaction::res::Res::OpenRes(Default::default()))], false))
+            }
+            Some(transaction::req::Req::CommitReq(_)) => {
+                self.check(MockRpc::Commit)?;
+                Ok((vec![single_res(req_id, transaction::res::Res::CommitRes(Default::default()))], true))
+            }
+            Some(transaction::req::Req::RollbackReq(_)) => {
+                Ok((vec![single_res(req_id, transaction::res::Res::RollbackRes(Default::default()))], false))
+            }
+            Some(transaction::req::Req::QueryManagerReq(query_manager::Req { req: Some(query_req), .. })) => {
+                Ok((self.handle_query(req_id, query_req, state)?, false))
+            }
+            Some(transaction::req::Req::StreamReq(_)) => {
+                self.node.lock().unwrap().stream_continuations += 1;
+                match state.remaining_pages.remove(&req_id) {
+                    Some((remaining, kind)) => Ok((next_page(req_id, remaining, kind, state), false)),
+                    None => Ok((Vec::new(), false)),
+                }
+            }
+            _ => Err(Status::unimplemented("The mock server does not support this transaction request.")),
+        }
+    }
+
+    fn handle_query(
+        &self,
+        req_id: Vec<u8>,
+        query_req: query_manager::req::Req,
+        state: &mut TransactionState,
+    ) -> Result<Vec<transaction::Server>, Status> {
+        use query_manager::req::Req;
+        self.check(MockRpc::Query)?;
+        let (query, kind) = match query_req {
+            Req::DefineReq(req) => {
+                if let Some(database) = &state.database {
+                    let mut cluster = self.cluster.lock().unwrap();
+                    if let Some(schema) = cluster.databases.get_mut(database) {
+                        schema.push_str(&req.query);
+                        schema.push('\n');
+                    }
+                }
+                (req.query, QueryKind::Single)
+            }
+            Req::UndefineReq(req) => (req.query, QueryKind::Single),
+            Req::DeleteReq(req) => (req.query, QueryKind::Single),
+            Req::MatchAggregateReq(req) => (req.query, QueryKind::Aggregate),
+            Req::MatchReq(req) => (req.query, QueryKind::Stream(StreamKind::Match)),
+            Req::InsertReq(req) => (req.query, QueryKind::Stream(StreamKind::Insert)),
+            Req::UpdateReq(req) => (req.query, QueryKind::Stream(StreamKind::Update)),
+            Req::MatchGroupReq(req) => (req.query, QueryKind::Stream(StreamKind::Match)),
+            Req::MatchGroupAggregateReq(req) => (req.query, QueryKind::Stream(StreamKind::Match)),
+            Req::ExplainReq(_) => (String::new(), QueryKind::Stream(StreamKind::Match)),
+        };
+
+        let scripted = self.cluster.lock().unwrap().scripted.get(&query).cloned();
+        let (aggregate, pages) = match scripted {
+            Some(MockResponse::Failure(failure)) => return Err(failure.to_status()),
+            Some(MockResponse::Aggregate(value)) => (value, 0),
+            Some(MockResponse::Pages(pages)) => (0, pages),
+            Some(MockResponse::Empty) | None => (0, 0),
+        };
+
+        let res = match kind {
+            QueryKind::Single => {
+                query_manager::Res { res: Some(query_manager::res::Res::DefineRes(Default::default())) }
+            }
+            QueryKind::Aggregate => query_manager::Res {
+                res: Some(query_manager::res::Res::MatchAggregateRes(query_manager::match_aggregate::Res {
+                    answer: Some(Numeric { value: Some(numeric::Value::LongValue(aggregate)) }),
+                })),
+            },
+            QueryKind::Stream(kind) => return Ok(next_page(req_id, pages, kind, state)),
+        };
+        Ok(vec![single_res(req_id, transaction::res::Res::QueryManagerRes(res))])
+    }
+}
+
+fn next_page(
+    req_id: Vec<u8>,
+    remaining: usize,
+    kind: StreamKind,
+    state: &mut TransactionState,
+) -> Vec<transaction::Server> {
+    use query_manager::res_part::Res;
+    if remaining == 0 {
+        return vec![stream_res_part(req_id, transaction::stream::State::Done)];
+    }
+    let answers = vec![mock_answer(remaining)];
+    let res = match kind {
+        StreamKind::Match => Res::MatchResPart(query_manager::r#match::ResPart { answers }),
+        StreamKind::Insert => Res::InsertResPart(query_manager::insert::ResPart { answers }),
+        StreamKind::Update => Res::UpdateResPart(query_manager::update::ResPart { answers }),
+    };
+    let page = transaction::res_part::Res::QueryManagerResPart(query_manager::ResPart { res: Some(res) });
+    let page = transaction::Server {
+        server: Some(transaction::server::Server::ResPart(transaction::ResPart {
+            req_id: req_id.clone(),
+            res: Some(page),
+        })),
+    };
+    state.remaining_pages.insert(req_id.clone(), (remaining - 1, kind));
+    vec![page, stream_res_part(req_id, transaction::stream::State::Continue)]
+}
+
+fn mock_answer(index: usize) -> ConceptMap {
+    let thing = Thing {
+        iid: (index as u64).to_be_bytes().to_vec(),
+        r#type: Some(Type {
+            label: String::from("mock-entity"),
+            encoding: r#type::Encoding::EntityType as i32,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let concept = Concept { concept: Some(typedb_protocol::concept::Concept::Thing(thing)) };
+    ConceptMap { map: HashMap::from([(String::from("x"), concept)]), explainables: None }
+}
+
+fn stream_res_part(req_id: Vec<u8>, state: transaction::stream::State) -> transaction::Server {
+    transaction::Server {
+        server: Some(transaction::server::Server::ResPart(transaction::ResPart {
+            req_id,
+            res: Some(transaction::res_part::Res::StreamResPart(transaction::stream::ResPart { state: state as i32 })),
+        })),
+    }
+}
+
+fn single_res(req_id: Vec<u8>, res: transaction::res::Res) -> transaction::Server {
+    transaction::Server { server: Some(transaction::server::Server::Res(transaction::Res { req_id, res: Some(res) })) }
+}
+
+fn database_not_found(name: &str) -> Status {
+    Status::internal(format!("[DBS01] Database '{name}' does not exist."))
+}
+
+fn session_not_found() -> Status {
+    Status::internal("[SSN01] Session not found.")
+}
+
+fn user_not_found(username: &str) -> Status {
+    Status::internal(format!("[CLS03] User '{username}' does not exist."))
+}
+
+#[tonic::async_trait]
+impl type_db_server::TypeDb for MockService {
+    type TransactionStream = UnboundedReceiverStream<Result<transaction::Server, Status>>;
+
+    async fn databases_contains(
+        &self,
+        request: Request<core_database_manager::contains::Req>,
+    ) -> ServiceResult<core_database_manager::contains::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        let contains = self.cluster.lock().unwrap().databases.contains_key(&request.into_inner().name);
+        Ok(Response::new(core_database_manager::contains::Res { contains }))
+    }
+
+    async fn databases_create(
+        &self,
+        request: Request<core_database_manager::create::Req>,
+    ) -> ServiceResult<core_database_manager::create::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        self.check_primary()?;
+        let name = request.into_inner().name;
+        let mut cluster = self.cluster.lock().unwrap();
+        if cluster.databases.contains_key(&name) {
+            return Err(Status::internal(format!("[DBS03] Database '{name}' already exists.")));
+        }
+        cluster.databases.insert(name, String::new());
+        Ok(Response::new(core_database_manager::create::Res {}))
+    }
+
+    async fn databases_all(
+        &self,
+        _request: Request<core_database_manager::all::Req>,
+    ) -> ServiceResult<core_database_manager::all::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        let names = self.cluster.lock().unwrap().databases.keys().cloned().collect();
+        Ok(Response::new(core_database_manager::all::Res { names }))
+    }
+
+    async fn database_schema(
+        &self,
+        request: Request<core_database::schema::Req>,
+    ) -> ServiceResult<core_database::schema::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        let schema = self.database_schema(&request.into_inner().name)?;
+        Ok(Response::new(core_database::schema::Res { schema }))
+    }
+
+    async fn database_type_schema(
+        &self,
+        request: Request<core_database::type_schema::Req>,
+    ) -> ServiceResult<core_database::type_schema::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        let schema = self.database_schema(&request.into_inner().name)?;
+        Ok(Response::new(core_database::type_schema::Res { schema }))
+    }
+
+    async fn database_rule_schema(
+        &self,
+        request: Request<core_database::rule_schema::Req>,
+    ) -> ServiceResult<core_database::rule_schema::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        self.database_schema(&request.into_inner().name)?;
+        Ok(Response::new(core_database::rule_schema::Res { schema: String::new() }))
+    }
+
+    async fn database_delete(
+        &self,
+        request: Request<core_database::delete::Req>,
+    ) -> ServiceResult<core_database::delete::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        self.check_primary()?;
+        let name = request.into_inner().name;
+        let mut cluster = self.cluster.lock().unwrap();
+        cluster.databases.remove(&name).ok_or_else(|| database_not_found(&name))?;
+        cluster.sessions.retain(|_, database| *database != name);
+        Ok(Response::new(core_database::delete::Res {}))
+    }
+
+    async fn session_open(&self, request: Request<session::open::Req>) -> ServiceResult<session::open::Res> {
+        self.check(MockRpc::Session)?;
+        let req = request.into_inner();
+        let mut cluster = self.cluster.lock().unwrap();
+        if !cluster.databases.contains_key(&req.database) {
+            return Err(database_not_found(&req.database));
+        }
+        let session_id = Uuid::new_v4().as_bytes().to_vec();
+        cluster.sessions.insert(session_id.clone(), req.database);
+        Ok(Response::new(session::open::Res { session_id, server_duration_millis: 0 }))
+    }
+
+    async fn session_close(&self, request: Request<session::close::Req>) -> ServiceResult<session::close::Res> {
+        self.cluster.lock().unwrap().sessions.remove(&request.into_inner().session_id);
+        Ok(Response::new(session::close::Res {}))
+    }
+
+    async fn session_pulse(&self, request: Request<session::pulse::Req>) -> ServiceResult<session::pulse::Res> {
+        let alive = self.cluster.lock().unwrap().sessions.contains_key(&request.into_inner().session_id);
+        Ok(Response::new(session::pulse::Res { alive }))
+    }
+
+    async fn transaction(
+        &self,
+        request: Request<Streaming<transaction::Client>>,
+    ) -> ServiceResult<Self::TransactionStream> {
+        let mut requests = request.into_inner();
+        let mut state = TransactionState::default();
+        let (sink, source) = unbounded_channel();
+        // The client sends the open request on its own, and a transaction that cannot be opened fails the RPC
+        // itself, so that the client can fail over to another replica.
+        if let Some(client) = requests.next().await {
+            for req in client?.reqs {
+                let (responses, _) = self.handle_transaction_req(req, &mut state)?;
+                responses.into_iter().for_each(|res| {
+                    sink.send(Ok(res)).ok();
+                });
+            }
+        }
+        tokio::spawn(self.clone().run_transaction(requests, state, sink));
+        Ok(Response::new(UnboundedReceiverStream::new(source)))
+    }
+}
+
+#[tonic::async_trait]
+impl type_db_cluster_server::TypeDbCluster for MockService {
+    async fn servers_all(
+        &self,
+        _request: Request<server_manager::all::Req>,
+    ) -> ServiceResult<server_manager::all::Res> {
+        let cluster = self.cluster.lock().unwrap();
+        Ok(Response::new(server_manager::all::Res {
+            servers: cluster.members().map(|(_, address)| Server { address: address.clone() }).collect(),
+        }))
+    }
+
+    async fn user_token(&self, request: Request<cluster_user::token::Req>) -> ServiceResult<cluster_user::token::Res> {
+        let username = request.into_inner().username;
+        Ok(Response::new(cluster_user::token::Res { token: format!("mock-token-{username}") }))
+    }
+
+    async fn users_contains(
+        &self,
+        request: Request<cluster_user_manager::contains::Req>,
+    ) -> ServiceResult<cluster_user_manager::contains::Res> {
+        let contains = self.cluster.lock().unwrap().users.contains_key(&request.into_inner().username);
+        Ok(Response::new(cluster_user_manager::contains::Res { contains }))
+    }
+
+    async fn users_create(
+        &self,
+        request: Request<cluster_user_manager::create::Req>,
+    ) -> ServiceResult<cluster_user_manager::create::Res> {
+        self.check_primary()?;
+        let req = request.into_inner();
+        self.cluster.lock().unwrap().users.insert(req.username, req.password);
+        Ok(Response::new(cluster_user_manager::create::Res {}))
+    }
+
+    async fn users_delete(
+        &self,
+        request: Request<cluster_user_manager::delete::Req>,
+    ) -> ServiceResult<cluster_user_manager::delete::Res> {
+        self.check_primary()?;
+        let username = request.into_inner().username;
+        self.cluster.lock().unwrap().users.remove(&username).ok_or_else(|| user_not_found(&username))?;
+        Ok(Response::new(cluster_user_manager::delete::Res {}))
+    }
+
+    async fn users_all(
+        &self,
+        _request: Request<cluster_user_manager::all::Req>,
+    ) -> ServiceResult<cluster_user_manager::all::Res> {
+        let users = (self.cluster.lock().unwrap().users.keys())
+            .map(|username| ClusterUser { username: username.clone(), ..Default::default() })
+            .collect();
+        Ok(Response::new(cluster_user_manager::all::Res { users }))
+    }
+
+    async fn users_get(
+        &self,
+        request: Request<cluster_user_manager::get::Req>,
+    ) -> ServiceResult<cluster_user_manager::get::Res> {
+        let username = request.into_inner().username;
+        let user = (self.cluster.lock().unwrap().users.contains_key(&username))
+            .then(|| ClusterUser { username, ..Default::default() });
+        Ok(Response::new(cluster_user_manager::get::Res { user }))
+    }
+
+    async fn users_password_set(
+        &self,
+        request: Request<cluster_user_manager::password_set::Req>,
+    ) -> ServiceResult<cluster_user_manager::password_set::Res> {
+        self.check_primary()?;
+        let req = request.into_inner();
+        let mut cluster = self.cluster.lock().unwrap();
+        let password = cluster.users.get_mut(&req.username).ok_or_else(|| user_not_found(&req.username))?;
+        *password = req.password;
+        Ok(Response::new(cluster_user_manager::password_set::Res {}))
+    }
+
+    async fn user_password_update(
+        &self,
+        request: Request<cluster_user::password_update::Req>,
+    ) -> ServiceResult<cluster_user::password_update::Res> {
+        self.check_primary()?;
+        let req = request.into_inner();
+        let mut cluster = self.cluster.lock().unwrap();
+        let password = cluster.users.get_mut(&req.username).ok_or_else(|| user_not_found(&req.username))?;
+        if *password != req.password_old {
+            return Err(Status::internal("[CLS07] The old password is incorrect."));
+        }
+        *password = req.password_new;
+        Ok(Response::new(cluster_user::password_update::Res {}))
+    }
+
+    async fn databases_get(
+        &self,
+        request: Request<cluster_database_manager::get::Req>,
+    ) -> ServiceResult<cluster_database_manager::get::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        let name = request.into_inner().name;
+        let cluster = self.cluster.lock().unwrap();
+        Ok(Response::new(cluster_database_manager::get::Res {
+            database: Some(cluster.cluster_database(name, self.index)),
+        }))
+    }
+
+    async fn databases_all(
+        &self,
+        _request: Request<cluster_database_manager::all::Req>,
+    ) -> ServiceResult<cluster_database_manager::all::Res> {
+        self.check(MockRpc::DatabaseManagement)?;
+        let cluster = self.cluster.lock().unwrap();
+        let databases =
+            cluster.databases.keys().map(|name| cluster.cluster_database(name.clone(), self.index)).collect();
+        Ok(Response::new(cluster_database_manager::all::Res { databases }))
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::fmt;
+
+use super::UserManager;
+use crate::{common::info::UserInfo, Connection, Result};
+
+pub struct User {
+    username: String,
+    password_expiry_seconds: Option<i64>,
+    connection: Connection,
+}
+
+impl User {
+    const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+    pub(super) fn new(user_info: UserInfo, connection: Connection) -> Self {
+        Self { username: user_info.username, password_expiry_seconds: user_info.password_expiry_seconds, connection }
+    }
+
+    pub fn username(&self) -> &str {
+        self.username.as_str()
+    }
+
+    pub fn password_expiry_seconds(&self) -> Option<i64> {
+        self.password_expiry_seconds
+    }
+
+    pub fn password_expiry_days(&self) -> Option<i64> {
+        self.password_expiry_seconds.map(|seconds| seconds / Self::SECONDS_PER_DAY)
+    }
+
+    pub async fn password_update(&self, password_old: impl Into<String>, password_new: impl Into<String>) -> Result {
+        let (password_old, password_new) = (password_old.into(), password_new.into());
+        UserManager::new(self.connection.clone())
+            .run_on_primary_server(|server_connection| {
+                let (username, password_old, password_new) =
+                    (self.username.clone(), password_old.clone(), password_new.clone());
+                async move { server_connection.update_user_password(username, password_old, password_new).await }
+            })
+            .await
+    }
+}
+
+impl fmt::Debug for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("User")
+            .field("username", &self.username)
+            .field("password_expiry_seconds", &self.password_expiry_seconds)
+            .finish()
+    }
+}
@@ -0,0 +1,109 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::future::Future;
+
+use super::User;
+use crate::{
+    common::{error::ConnectionError, Result},
+    connection::ServerConnection,
+    Connection, DatabaseManager,
+};
+
+#[derive(Clone, Debug)]
+pub struct UserManager {
+    connection: Connection,
+}
+
+impl UserManager {
+    const SYSTEM_DB: &'static str = "_system";
+
+    pub fn new(connection: Connection) -> Self {
+        Self { connection }
+    }
+
+    pub async fn contains(&self, username: impl Into<String>) -> Result<bool> {
+        let username = username.into();
+        self.run_on_primary_server(|server_connection| {
+            let username = username.clone();
+            async move { server_connection.user_exists(username).await }
+        })
+        .await
+    }
+
+    pub async fn create(&self, username: impl Into<String>, password: impl Into<String>) -> Result {
+        let (username, password) = (username.into(), password.into());
+        self.run_on_primary_server(|server_connection| {
+            let (username, password) = (username.clone(), password.clone());
+            async move { server_connection.create_user(username, password).await }
+        })
+        .await
+    }
+
+    pub async fn delete(&self, username: impl Into<String>) -> Result {
+        let username = username.into();
+        self.run_on_primary_server(|server_connection| {
+            let username = username.clone();
+            async move { server_connection.delete_user(username).await }
+        })
+        .await
+    }
+
+    pub async fn all(&self) -> Result<Vec<User>> {
+        let users =
+            self.run_on_primary_server(|server_connection| async move { server_connection.all_users().await }).await?;
+        Ok(users.into_iter().map(|user_info| User::new(user_info, self.connection.clone())).collect())
+    }
+
+    pub async fn get(&self, username: impl Into<String>) -> Result<Option<User>> {
+        let username = username.into();
+        if !self.contains(username.clone()).await? {
+            return Ok(None);
+        }
+        let user = self
+            .run_on_primary_server(|server_connection| {
+                let username = username.clone();
+                async move { server_connection.get_user(username).await }
+            })
+            .await?;
+        Ok(user.map(|user_info| User::new(user_info, self.connection.clone())))
+    }
+
+    pub async fn password_set(&self, username: impl Into<String>, password: impl Into<String>) -> Result {
+        let (username, password) = (username.into(), password.into());
+        self.run_on_primary_server(|server_connection| {
+            let (username, password) = (username.clone(), password.clone());
+            async move { server_connection.set_user_password(username, password).await }
+        })
+        .await
+    }
+
+    pub(super) async fn run_on_primary_server<F, P, R>(&self, task: F) -> Result<R>
+    where
+        F: Fn(ServerConnection) -> P,
+        P: Future<Output = Result<R>>,
+    {
+        if !self.connection.is_encrypted() {
+            return Err(ConnectionError::UserManagementClusterOnly().into());
+        }
+        DatabaseManager::new(self.connection.clone()).get(Self::SYSTEM_DB).await?.run_on_primary_server(task).await
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use typedb_client::{
+    answer::{ConceptMap, Explainables, FromConceptMap},
+    concept::{Attribute, Concept, DateTimeAttribute, Entity, EntityType, StringAttribute, Thing},
+    error::ConceptError,
+    Error,
+};
+
+#[derive(FromConceptMap)]
+struct Person {
+    #[typedb(iid)]
+    x: Vec<u8>,
+    name: String,
+    #[typedb(var = "date-of-birth")]
+    date_of_birth: NaiveDateTime,
+    nickname: Option<String>,
+}
+
+fn concept_map(concepts: impl IntoIterator<Item = (&'static str, Thing)>) -> ConceptMap {
+    ConceptMap {
+        map: concepts.into_iter().map(|(variable, thing)| (variable.to_owned(), Concept::Thing(thing))).collect(),
+        explainables: Explainables::new(HashMap::new(), HashMap::new(), HashMap::new()),
+    }
+}
+
+#[test]
+fn derived_fields_are_read_from_variables() -> typedb_client::Result {
+    let date_of_birth = NaiveDate::from_ymd_opt(1994, 10, 3).unwrap().and_hms_opt(0, 0, 0).unwrap();
+    let person = Person::from_concept_map(concept_map([
+        ("x", Thing::Entity(Entity { iid: vec![0xaa, 0x01], type_: EntityType::new(String::from("person")) })),
+        (
+            "name",
+            Thing::Attribute(Attribute::String(StringAttribute { iid: vec![0x02], value: String::from("Alice") })),
+        ),
+        (
+            "date-of-birth",
+            Thing::Attribute(Attribute::DateTime(DateTimeAttribute { iid: vec![0x03], value: date_of_birth })),
+        ),
+    ]))?;
+
+    assert_eq!(person.x, vec![0xaa, 0x01]);
+    assert_eq!(person.name, "Alice");
+    assert_eq!(person.date_of_birth, date_of_birth);
+    assert!(person.nickname.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn derived_fields_report_missing_variables() {
+    let result = Person::from_concept_map(concept_map([(
+        "x",
+        Thing::Entity(Entity { iid: vec![0xaa, 0x01], type_: EntityType::new(String::from("person")) }),
+    )]));
+    assert!(matches!(result, Err(Error::Concept(ConceptError::MissingVariable(variable))) if variable == "name"));
+}
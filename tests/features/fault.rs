@@ -0,0 +1,162 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::time::{Duration, Instant};
+
+use futures::future::try_join_all;
+use tokio::sync::oneshot;
+use typedb_client::{
+    error::ConnectionError,
+    testing::{Fault, FaultPolicy, MockCluster, MockResponse, MockServer},
+    Connection, ConnectionSettings, Credential, DatabaseManager, Error, Session,
+    SessionType::Data,
+    TransactionType::{Read, Write},
+};
+
+const FAULT_DATABASE: &str = "fault";
+
+fn connect(server: &MockServer, policy: &FaultPolicy) -> typedb_client::Result<DatabaseManager> {
+    let settings = ConnectionSettings::new().fault_policy(policy.clone());
+    Ok(DatabaseManager::new(Connection::new_plaintext_with_settings(server.address(), settings)?))
+}
+
+#[tokio::test]
+async fn injected_unavailable() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let policy = FaultPolicy::new();
+    let databases = connect(&server, &policy)?;
+
+    policy.fail_next(Fault::Unavailable, 1);
+    assert!(matches!(
+        databases.contains(FAULT_DATABASE).await,
+        Err(Error::Connection(ConnectionError::ClusterUnableToConnect(_)))
+    ));
+    assert!(!databases.contains(FAULT_DATABASE).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn injected_latency() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let policy = FaultPolicy::new();
+    let databases = connect(&server, &policy)?;
+
+    policy.set_latency(Some(Duration::from_millis(200)));
+    let start = Instant::now();
+    databases.create(FAULT_DATABASE).await?;
+    assert!(start.elapsed() >= Duration::from_millis(200));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn slow_server_is_reported_unreachable() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let policy = FaultPolicy::new();
+    let settings = ConnectionSettings::new().request_timeout(Duration::from_millis(100)).fault_policy(policy.clone());
+    let connection = Connection::new_plaintext_with_settings(server.address(), settings)?;
+
+    let statuses = connection.servers_status().await?;
+    assert!(statuses[0].is_reachable);
+    assert!(statuses[0].latency.is_some());
+
+    policy.set_latency(Some(Duration::from_secs(2)));
+    let start = Instant::now();
+    let statuses = connection.servers_status().await?;
+    assert!(start.elapsed() < Duration::from_secs(2));
+    assert!(!statuses[0].is_reachable);
+    assert!(statuses[0].latency.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn dropped_transaction_stream() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let policy = FaultPolicy::new();
+    let databases = connect(&server, &policy)?;
+    databases.create(FAULT_DATABASE).await?;
+    let session = Session::new(databases.get(FAULT_DATABASE).await?, Data).await?;
+
+    policy.drop_transaction_streams_after(Some(1));
+    let transaction = session.transaction(Write).await?;
+    let (close_sink, close_source) = oneshot::channel();
+    transaction.on_close(move |reason| {
+        close_sink.send(reason).ok();
+    });
+
+    assert!(transaction.query().delete("match $x isa person; delete $x isa person;").await.is_err());
+    assert!(matches!(close_source.await, Ok(Some(Error::Connection(ConnectionError::UnableToConnect())))));
+
+    policy.reset();
+    let transaction = session.transaction(Write).await?;
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn reordered_transaction_responses() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let policy = FaultPolicy::new();
+    let databases = connect(&server, &policy)?;
+    databases.create(FAULT_DATABASE).await?;
+
+    let queries: Vec<_> = (0..10).map(|i| format!("match $x isa person, has age {i}; count;")).collect();
+    for (i, query) in queries.iter().enumerate() {
+        server.on_query(query.as_str(), MockResponse::Aggregate(i as i64));
+    }
+
+    policy.reorder_transaction_responses(true);
+    let session = Session::new(databases.get(FAULT_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let counts = try_join_all(queries.iter().map(|query| transaction.query().match_aggregate(query))).await?;
+    for (i, count) in counts.into_iter().enumerate() {
+        assert_eq!(count.into_i64(), i as i64);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unavailable_primary_is_retried() -> typedb_client::Result {
+    let cluster = MockCluster::start(3)?;
+    let policy = FaultPolicy::new();
+    let settings = ConnectionSettings::new()
+        .primary_replica_selection_wait(Duration::from_millis(10))
+        .fault_policy(policy.clone());
+    let connection = Connection::new_encrypted_with_settings(
+        &cluster.addresses(),
+        Credential::without_tls("admin", "password"),
+        settings,
+    )?;
+    let databases = DatabaseManager::new(connection);
+    databases.create(FAULT_DATABASE).await?;
+    let database = databases.get(FAULT_DATABASE).await?;
+
+    policy.fail_next_at(cluster.server(cluster.primary()).address(), Fault::RstStream, 1)?;
+    let session = Session::new(database, Data).await?;
+    let transaction = session.transaction(Write).await?;
+    transaction.commit().await?;
+
+    Ok(())
+}
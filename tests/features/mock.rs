@@ -0,0 +1,376 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use futures::StreamExt;
+use tokio::{
+    sync::oneshot,
+    time::{sleep, timeout},
+};
+use typedb_client::{
+    error::ConnectionError,
+    testing::{MockCluster, MockFailure, MockResponse, MockRpc, MockServer},
+    Connection, ConnectionSettings, Credential, DatabaseManager, Error, Options, Session,
+    SessionType::{Data, Schema},
+    TransactionType::{Read, Write},
+};
+
+const MOCK_DATABASE: &str = "mock";
+
+#[tokio::test]
+async fn mock_server_queries() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    server.on_query("match $x isa person; count;", MockResponse::Aggregate(42));
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+
+    databases.create(MOCK_DATABASE).await?;
+    assert!(databases.contains(MOCK_DATABASE).await?);
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Schema).await?;
+    let transaction = session.transaction(Write).await?;
+    transaction.query().define("define person sub entity;").await?;
+    transaction.commit().await?;
+    assert!(databases.get(MOCK_DATABASE).await?.schema().await?.contains("person sub entity"));
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let people: Vec<_> = transaction.query().match_("match $x isa person;")?.collect().await;
+    assert!(people.is_empty());
+    let count = transaction.query().match_aggregate("match $x isa person; count;").await?;
+    assert_eq!(count.into_i64(), 42);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_query_failure() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    server.on_query(
+        "match $x isa nonexistent-type;",
+        MockResponse::Failure(MockFailure::Server {
+            code: String::from("TYR03"),
+            message: String::from("Invalid Type Read: The type 'nonexistent-type' does not exist."),
+        }),
+    );
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(MOCK_DATABASE).await?;
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let answers: Vec<_> = transaction.query().match_("match $x isa nonexistent-type;")?.collect().await;
+    match answers.into_iter().next() {
+        Some(Err(Error::Server(error))) => assert_eq!(error.code, "TYR03"),
+        other => panic!("expected a server error, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_commit_conflict_is_retried() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let connect = |retry_limit| {
+        let settings = ConnectionSettings::new().transaction_retry_limit(retry_limit);
+        Connection::new_plaintext_with_settings(server.address(), settings).map(DatabaseManager::new)
+    };
+    let databases = connect(3)?;
+    databases.create(MOCK_DATABASE).await?;
+
+    server.fail_next(MockRpc::Commit, MockFailure::CommitConflict, 2);
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    session
+        .run_in_transaction(Write, Options::new(), |transaction| {
+            Box::pin(async move {
+                let _ = transaction.query().insert("insert $x isa person;")?.collect::<Vec<_>>().await;
+                Ok(())
+            })
+        })
+        .await?;
+
+    let databases = connect(1)?;
+    server.fail_next(MockRpc::Commit, MockFailure::CommitConflict, 2);
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let result = session.run_in_transaction(Write, Options::new(), |_| Box::pin(async move { Ok(()) })).await;
+    assert!(matches!(result, Err(Error::Server(error)) if error.is_commit_conflict()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_session_closed_by_server() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let settings = ConnectionSettings::new().session_pulse_interval(Duration::from_millis(10));
+    let databases = DatabaseManager::new(Connection::new_plaintext_with_settings(server.address(), settings)?);
+    databases.create(MOCK_DATABASE).await?;
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let (close_sink, close_source) = oneshot::channel();
+    session.on_close(move |reason| {
+        close_sink.send(reason).ok();
+    });
+
+    databases.get(MOCK_DATABASE).await?.delete().await?;
+    let reason = timeout(Duration::from_secs(5), close_source).await.expect("session was not closed").unwrap();
+    assert!(matches!(reason, Some(Error::Connection(ConnectionError::SessionIsClosed()))));
+    assert!(!session.is_open());
+    assert!(matches!(session.transaction(Read).await, Err(Error::Connection(ConnectionError::SessionIsClosed()))));
+
+    let (close_sink, close_source) = oneshot::channel();
+    session.on_close(move |reason| {
+        close_sink.send(reason).ok();
+    });
+    assert!(matches!(close_source.await, Ok(Some(Error::Connection(ConnectionError::SessionIsClosed())))));
+    session.force_close()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_dropped_stream_stops_continuations() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    server.on_query("match $x isa person;", MockResponse::Pages(1000));
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(MOCK_DATABASE).await?;
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let people: Vec<_> = transaction.query().match_("match $x isa person;")?.take(3).collect().await;
+    assert_eq!(people.len(), 3);
+
+    sleep(Duration::from_millis(100)).await;
+    assert!(server.stream_continuations() < 10);
+    assert_eq!(transaction.query().match_aggregate("match $x isa person; count;").await?.into_i64(), 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_cancelled_query() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    server.on_query("match $x isa person;", MockResponse::Pages(1000));
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(MOCK_DATABASE).await?;
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let (mut people, handle) = transaction.query().match_cancellable("match $x isa person;")?;
+    assert!(people.next().await.transpose()?.is_some());
+
+    handle.cancel();
+    assert!(handle.is_cancelled());
+    assert!(people.next().await.is_none());
+
+    sleep(Duration::from_millis(100)).await;
+    assert!(server.stream_continuations() < 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_dropped_insert_stream_is_fetched_in_full() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    server.on_query("insert $x isa person;", MockResponse::Pages(5));
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(MOCK_DATABASE).await?;
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Write).await?;
+    let inserted: Vec<_> = transaction.query().insert("insert $x isa person;")?.take(1).collect().await;
+    assert_eq!(inserted.len(), 1);
+
+    timeout(Duration::from_secs(5), async {
+        while server.stream_continuations() < 5 {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("insert stream was not fetched in full");
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_server_retry_backoff_does_not_block_executor() -> typedb_client::Result {
+    let server = MockServer::start()?;
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(MOCK_DATABASE).await?;
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+
+    let ticks = Arc::new(AtomicUsize::new(0));
+    let ticker = tokio::spawn({
+        let ticks = ticks.clone();
+        async move {
+            loop {
+                sleep(Duration::from_millis(5)).await;
+                ticks.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    });
+
+    server.fail_next(MockRpc::Commit, MockFailure::CommitConflict, 2);
+    let start = Instant::now();
+    session.run_in_transaction(Write, Options::new(), |_| Box::pin(async move { Ok(()) })).await?;
+    ticker.abort();
+
+    // Two retries back off for 100ms and 200ms on the test's single-threaded executor.
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert!(ticks.load(Ordering::Relaxed) >= 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_cluster_primary_failover() -> typedb_client::Result {
+    let cluster = MockCluster::start(3)?;
+    let connection = Connection::new_encrypted_with_settings(
+        &cluster.addresses(),
+        Credential::without_tls("admin", "password"),
+        ConnectionSettings::new().primary_replica_selection_wait(Duration::from_millis(10)),
+    )?;
+    let databases = DatabaseManager::new(connection);
+    databases.create(MOCK_DATABASE).await?;
+
+    let session = Session::new(databases.get(MOCK_DATABASE).await?, Data).await?;
+    cluster.elect_primary(1);
+
+    let transaction = session.transaction(Write).await?;
+    let _ = transaction.query().insert("insert $x isa person;")?.collect::<Vec<_>>().await;
+    transaction.commit().await?;
+    assert_eq!(cluster.primary(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_cluster_read_transaction_survives_write() -> typedb_client::Result {
+    let cluster = MockCluster::start(3)?;
+    cluster.on_query("match $x isa person; count;", MockResponse::Aggregate(1));
+    let connection = Connection::new_encrypted_with_settings(
+        &cluster.addresses(),
+        Credential::without_tls("admin", "password"),
+        ConnectionSettings::new().primary_replica_selection_wait(Duration::from_millis(10)),
+    )?;
+    let databases = DatabaseManager::new(connection);
+    databases.create(MOCK_DATABASE).await?;
+
+    // Whichever replica serves the reads, at least one of these primaries lives on another server.
+    for primary in 0..3 {
+        cluster.elect_primary(primary);
+        let database = databases.get(MOCK_DATABASE).await?;
+        let session = Session::new_with_options(database, Data, Options::new().read_any_replica(true)).await?;
+        let read_transaction = session.transaction(Read).await?;
+
+        let write_transaction = session.transaction(Write).await?;
+        let _ = write_transaction.query().insert("insert $x isa person;")?.collect::<Vec<_>>().await;
+        write_transaction.commit().await?;
+
+        assert!(read_transaction.is_open());
+        assert_eq!(read_transaction.query().match_aggregate("match $x isa person; count;").await?.into_i64(), 1);
+        let read_transaction = session.transaction(Read).await?;
+        assert_eq!(read_transaction.query().match_aggregate("match $x isa person; count;").await?.into_i64(), 1);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_cluster_schema_session_reads_on_primary() -> typedb_client::Result {
+    // Whichever replica is preferred, at least one of these primaries lives on another server.
+    for primary in 0..3 {
+        let cluster = MockCluster::start(3)?;
+        cluster.elect_primary(primary);
+        let connection = Connection::new_encrypted(&cluster.addresses(), Credential::without_tls("admin", "password"))?;
+        let databases = DatabaseManager::new(connection);
+        databases.create(MOCK_DATABASE).await?;
+
+        let database = databases.get(MOCK_DATABASE).await?;
+        let session = Session::new_with_options(database, Schema, Options::new().read_any_replica(true)).await?;
+        for secondary in (0..3).filter(|&index| index != primary) {
+            cluster.server(secondary).fail_next(
+                MockRpc::Session,
+                MockFailure::Server {
+                    code: String::from("SSN99"),
+                    message: String::from("Schema sessions can only be opened on the primary replica."),
+                },
+                1,
+            );
+        }
+
+        let transaction = session.transaction(Read).await?;
+        assert!(transaction.is_open());
+    }
+
+    Ok(())
+}
+
+async fn is_known_server(connection: &Connection, address: &str) -> typedb_client::Result<bool> {
+    let statuses = connection.servers_status().await?;
+    Ok(statuses.into_iter().any(|status| status.is_reachable && status.address.contains(address)))
+}
+
+#[tokio::test]
+async fn mock_cluster_refresh_servers() -> typedb_client::Result {
+    let mut cluster = MockCluster::start(2)?;
+    let connection = Connection::new_encrypted(&cluster.addresses(), Credential::without_tls("admin", "password"))?;
+
+    let added = cluster.add_server()?;
+    assert!(!is_known_server(&connection, cluster.server(added).address()).await?);
+    connection.refresh_servers()?;
+    assert!(is_known_server(&connection, cluster.server(added).address()).await?);
+
+    // A server is only dropped once it has been missing from several consecutive refreshes.
+    cluster.remove_server(1);
+    connection.refresh_servers()?;
+    connection.refresh_servers()?;
+    assert!(is_known_server(&connection, cluster.server(1).address()).await?);
+    connection.refresh_servers()?;
+    assert!(!is_known_server(&connection, cluster.server(1).address()).await?);
+    assert!(is_known_server(&connection, cluster.server(added).address()).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_cluster_periodic_server_refresh() -> typedb_client::Result {
+    let mut cluster = MockCluster::start(2)?;
+    let connection = Connection::new_encrypted_with_settings(
+        &cluster.addresses(),
+        Credential::without_tls("admin", "password"),
+        ConnectionSettings::new().server_refresh_interval(Duration::from_millis(10)),
+    )?;
+
+    let added = cluster.add_server()?;
+    let start = Instant::now();
+    while !is_known_server(&connection, cluster.server(added).address()).await? {
+        assert!(start.elapsed() < Duration::from_secs(5), "the new server was not picked up by the periodic refresh");
+        sleep(Duration::from_millis(10)).await;
+    }
+
+    Ok(())
+}
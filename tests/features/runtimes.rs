@@ -0,0 +1,99 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{
+    future::Future,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use futures::TryStreamExt;
+use typedb_client::{
+    testing::MockCluster, Connection, ConnectionSettings, Credential, DatabaseManager, Options, Session,
+    SessionType::Data, TransactionType::Write,
+};
+
+const RUNTIMES_DATABASE: &str = "runtimes";
+const PRIMARY_REPLICA_SELECTION_WAIT: Duration = Duration::from_millis(200);
+
+async fn concurrent_retries<Sleep: Future>(sleep: impl Fn(Duration) -> Sleep) -> typedb_client::Result {
+    let cluster = MockCluster::start(3)?;
+    let connection = Connection::new_encrypted_with_settings(
+        &cluster.addresses(),
+        Credential::without_tls("admin", "password"),
+        ConnectionSettings::new().primary_replica_selection_wait(PRIMARY_REPLICA_SELECTION_WAIT),
+    )?;
+    let databases = DatabaseManager::new(connection);
+    databases.create(RUNTIMES_DATABASE).await?;
+    let session = Session::new(databases.get(RUNTIMES_DATABASE).await?, Data).await?;
+
+    // Both writes hit the old primary first and have to wait for the new one to be selected.
+    cluster.elect_primary(1);
+
+    let ticks = AtomicUsize::new(0);
+    let done = AtomicBool::new(false);
+    let ticker = async {
+        while !done.load(Ordering::Relaxed) {
+            sleep(Duration::from_millis(5)).await;
+            ticks.fetch_add(1, Ordering::Relaxed);
+        }
+    };
+    let insert = || {
+        session.run_in_transaction(Write, Options::new(), |transaction| {
+            Box::pin(async move {
+                let _: Vec<_> = transaction.query().insert("insert $x isa person;")?.try_collect().await?;
+                Ok(())
+            })
+        })
+    };
+    let inserts = async {
+        let results = futures::join!(insert(), insert());
+        done.store(true, Ordering::Relaxed);
+        results
+    };
+
+    let start = Instant::now();
+    let ((first, second), ()) = futures::join!(inserts, ticker);
+    first?;
+    second?;
+
+    assert_eq!(cluster.primary(), 1);
+    assert!(start.elapsed() >= PRIMARY_REPLICA_SELECTION_WAIT);
+    // The ticker shares the test's single thread, so it only advances if the wait yields to it.
+    assert!(ticks.load(Ordering::Relaxed) >= 10);
+    Ok(())
+}
+
+#[test]
+fn concurrent_retries_tokio() -> typedb_client::Result {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    runtime.block_on(concurrent_retries(tokio::time::sleep))
+}
+
+#[test]
+fn concurrent_retries_async_std() -> typedb_client::Result {
+    async_std::task::block_on(concurrent_retries(async_std::task::sleep))
+}
+
+#[test]
+fn concurrent_retries_smol() -> typedb_client::Result {
+    smol::block_on(concurrent_retries(smol::Timer::after))
+}
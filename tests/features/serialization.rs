@@ -0,0 +1,107 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde_json::json;
+use typedb_client::{
+    answer::{ConceptMap, Explainable, Explainables},
+    concept::{
+        Attribute, Concept, DateTimeAttribute, Entity, EntityType, LongAttribute, StringAttribute, Thing, Value,
+    },
+};
+
+#[test]
+fn iid_round_trip() {
+    let entity = Entity { iid: vec![0x00, 0x1f, 0xab, 0xff], type_: EntityType::new(String::from("person")) };
+    let serialized = serde_json::to_value(&entity).unwrap();
+    assert_eq!(serialized["iid"], json!("0x001fabff"));
+
+    let deserialized: Entity = serde_json::from_value(serialized).unwrap();
+    assert_eq!(deserialized.iid, entity.iid);
+    assert_eq!(deserialized.type_.label, "person");
+
+    let unprefixed: Entity =
+        serde_json::from_value(json!({ "iid": "001fabff", "type_": { "label": "person" } })).unwrap();
+    assert_eq!(unprefixed.iid, entity.iid);
+    assert!(serde_json::from_value::<Entity>(json!({ "iid": "0x001", "type_": { "label": "person" } })).is_err());
+    assert!(serde_json::from_value::<Entity>(json!({ "iid": "0xzz", "type_": { "label": "person" } })).is_err());
+}
+
+#[test]
+fn date_time_round_trip() {
+    let value = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap().and_hms_milli_opt(12, 30, 45, 123).unwrap();
+    let attribute = DateTimeAttribute { iid: vec![0x01], value };
+    let serialized = serde_json::to_value(&attribute).unwrap();
+    assert_eq!(serialized["value"], json!("2023-01-31T12:30:45.123"));
+    assert_eq!(serde_json::from_value::<DateTimeAttribute>(serialized).unwrap().value, value);
+
+    let whole_seconds = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap().and_hms_opt(12, 30, 45).unwrap();
+    let serialized = serde_json::to_value(Value::DateTime(whole_seconds)).unwrap();
+    assert_eq!(serialized, json!({ "DateTime": "2023-01-31T12:30:45" }));
+    assert_eq!(serde_json::from_value::<Value>(serialized).unwrap(), Value::DateTime(whole_seconds));
+}
+
+#[test]
+fn attribute_round_trip() {
+    let attributes = vec![
+        Attribute::Long(LongAttribute { iid: vec![0x02], value: 42 }),
+        Attribute::String(StringAttribute { iid: vec![0x03], value: String::from("Alice") }),
+    ];
+    let serialized = serde_json::to_string(&attributes).unwrap();
+    let deserialized: Vec<Attribute> = serde_json::from_str(&serialized).unwrap();
+    match deserialized.as_slice() {
+        [Attribute::Long(long), Attribute::String(string)] => {
+            assert_eq!((long.iid.as_slice(), long.value), ([0x02].as_slice(), 42));
+            assert_eq!((string.iid.as_slice(), string.value.as_str()), ([0x03].as_slice(), "Alice"));
+        }
+        other => panic!("unexpected attributes after round trip: {other:?}"),
+    }
+}
+
+#[test]
+fn concept_map_round_trip() {
+    let person = Entity { iid: vec![0xaa, 0x01], type_: EntityType::new(String::from("person")) };
+    let name = Attribute::String(StringAttribute { iid: vec![0xbb, 0x02], value: String::from("Alice") });
+    let explainable = Explainable::new(String::from("{ $x has $n; }"), 7);
+    let concept_map = ConceptMap {
+        map: HashMap::from([
+            (String::from("x"), Concept::Thing(Thing::Entity(person))),
+            (String::from("n"), Concept::Thing(Thing::Attribute(name))),
+        ]),
+        explainables: Explainables::new(
+            HashMap::new(),
+            HashMap::new(),
+            HashMap::from([((String::from("x"), String::from("n")), explainable.clone())]),
+        ),
+    };
+
+    let serialized = serde_json::to_value(&concept_map).unwrap();
+    assert_eq!(serialized["map"]["x"]["Thing"]["Entity"]["iid"], json!("0xaa01"));
+    let deserialized: ConceptMap = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&deserialized).unwrap(), serialized);
+    assert_eq!(deserialized.explainables().ownership("x", "n"), Some(&explainable));
+    match deserialized.get("n") {
+        Some(Concept::Thing(Thing::Attribute(Attribute::String(name)))) => assert_eq!(name.value, "Alice"),
+        other => panic!("unexpected concept after round trip: {other:?}"),
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use futures::TryStreamExt;
+use opentelemetry::{
+    global,
+    sdk::{propagation::TraceContextPropagator, trace::TracerProvider},
+    trace::{TraceContextExt, TracerProvider as _},
+};
+use tracing::{
+    field::{Field, Visit},
+    info_span,
+    span::{Attributes, Id, Record},
+    Instrument, Subscriber,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    Layer, Registry,
+};
+use typedb_client::{
+    testing::{MockResponse, MockServer},
+    Connection, DatabaseManager, Session,
+    SessionType::Data,
+    TransactionType::Read,
+};
+
+#[derive(Clone, Debug)]
+struct RecordedSpan {
+    name: &'static str,
+    parent: Option<usize>,
+    fields: HashMap<&'static str, String>,
+}
+
+impl RecordedSpan {
+    fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+#[derive(Debug, Default)]
+struct RecordedSpans {
+    spans: Vec<RecordedSpan>,
+    // Span IDs are reused once a span closes, so they are only mapped to a recorded span while it is open.
+    open: HashMap<u64, usize>,
+}
+
+struct FieldRecorder<'a>(&'a mut HashMap<&'static str, String>);
+
+impl Visit for FieldRecorder<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name(), value.to_owned());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+#[derive(Clone, Default)]
+struct RecordingLayer {
+    recorded: Arc<Mutex<RecordedSpans>>,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for RecordingLayer {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut recorded = self.recorded.lock().unwrap();
+        let parent = ctx
+            .span(id)
+            .and_then(|span| span.parent())
+            .and_then(|parent| recorded.open.get(&parent.id().into_u64()).copied());
+        let mut span = RecordedSpan { name: attrs.metadata().name(), parent, fields: HashMap::new() };
+        attrs.record(&mut FieldRecorder(&mut span.fields));
+        let index = recorded.spans.len();
+        recorded.spans.push(span);
+        recorded.open.insert(id.into_u64(), index);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut recorded = self.recorded.lock().unwrap();
+        if let Some(&index) = recorded.open.get(&id.into_u64()) {
+            values.record(&mut FieldRecorder(&mut recorded.spans[index].fields));
+        }
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        self.recorded.lock().unwrap().open.remove(&id.into_u64());
+    }
+}
+
+static RECORDING_LAYER: Mutex<Option<RecordingLayer>> = Mutex::new(None);
+
+/// Spans are created on the client's background runtime as well as on the test's thread, so the subscriber has to
+/// be the global default. It is installed once and shared by all tests in this module.
+fn recorded_spans() -> Arc<Mutex<RecordedSpans>> {
+    let mut recording_layer = RECORDING_LAYER.lock().unwrap();
+    if recording_layer.is_none() {
+        let layer = RecordingLayer::default();
+        // The tracer only holds a weak reference to its provider, which is kept alive by registering it globally.
+        let provider = TracerProvider::builder().build();
+        let tracer = provider.tracer("typedb-client-tests");
+        global::set_tracer_provider(provider);
+        let subscriber =
+            Registry::default().with(layer.clone()).with(tracing_opentelemetry::layer().with_tracer(tracer));
+        tracing::subscriber::set_global_default(subscriber).unwrap();
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        *recording_layer = Some(layer);
+    }
+    recording_layer.as_ref().unwrap().recorded.clone()
+}
+
+#[tokio::test]
+async fn spans_record_request_attributes() -> typedb_client::Result {
+    const DATABASE: &str = "trace-spans";
+    let recorded = recorded_spans();
+    let server = MockServer::start()?;
+    server.on_query("match $x isa person;", MockResponse::Pages(3));
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(DATABASE).await?;
+
+    let session = Session::new(databases.get(DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let answers: Vec<_> = transaction.query().match_("match $x isa person;")?.try_collect().await?;
+    assert_eq!(answers.len(), 3);
+    transaction.close().await?;
+    session.force_close()?;
+
+    let spans = recorded.lock().unwrap().spans.clone();
+    let database_rpc = spans
+        .iter()
+        .find(|span| span.name == "typedb.rpc" && span.field("database") == Some(DATABASE))
+        .expect("no RPC span was recorded for the database");
+    assert!(database_rpc.field("replica_address").unwrap().contains(server.address()));
+
+    let (transaction_index, transaction_span) = spans
+        .iter()
+        .enumerate()
+        .find(|(_, span)| span.name == "typedb.transaction" && span.field("database") == Some(DATABASE))
+        .expect("no transaction span was recorded");
+    assert_eq!(transaction_span.field("transaction_type"), Some("Read"));
+    assert!(transaction_span.field("replica_address").unwrap().contains(server.address()));
+    let session_id = transaction_span.field("session_id").expect("the transaction span has no session ID");
+    assert!(spans.iter().any(|span| span.name == "typedb.rpc"
+        && span.field("otel.name") == Some("session_close")
+        && span.field("session_id") == Some(session_id)));
+
+    let query_span = spans
+        .iter()
+        .find(|span| span.name == "typedb.query" && span.parent == Some(transaction_index))
+        .expect("no query span was recorded under the transaction span");
+    assert_eq!(query_span.field("otel.name"), Some("match"));
+    assert!(query_span.field("request_id").is_some());
+    assert_eq!(query_span.field("answer_count"), Some("3"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn trace_context_is_sent_to_the_server() -> typedb_client::Result {
+    recorded_spans();
+    let server = MockServer::start()?;
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+
+    let root = info_span!("trace_context_test");
+    let trace_id = root.context().span().span_context().trace_id();
+    databases.create("trace-context").instrument(root).await?;
+
+    let trace_parents = server.received_metadata("traceparent");
+    assert!(!trace_parents.is_empty());
+    assert!(trace_parents.iter().any(|trace_parent| trace_parent.starts_with(&format!("00-{trace_id:032x}-"))));
+
+    Ok(())
+}
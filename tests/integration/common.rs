@@ -23,17 +23,26 @@ use std::path::PathBuf;
 
 use futures::TryFutureExt;
 use typedb_client::{
-    Connection, Credential, Database, DatabaseManager, Session, SessionType::Schema, TransactionType::Write,
+    Connection, ConnectionSettings, Credential, Database, DatabaseManager, Session, SessionType::Schema,
+    TransactionType::Write,
 };
 
 pub const TEST_DATABASE: &str = "test";
 
 pub fn new_core_connection() -> typedb_client::Result<Connection> {
-    Connection::new_plaintext("127.0.0.1:1729")
+    new_core_connection_with_settings(ConnectionSettings::default())
+}
+
+pub fn new_core_connection_with_settings(settings: ConnectionSettings) -> typedb_client::Result<Connection> {
+    Connection::new_plaintext_with_settings("127.0.0.1:1729", settings)
 }
 
 pub fn new_cluster_connection() -> typedb_client::Result<Connection> {
-    Connection::new_encrypted(
+    new_cluster_connection_with_settings(ConnectionSettings::default())
+}
+
+pub fn new_cluster_connection_with_settings(settings: ConnectionSettings) -> typedb_client::Result<Connection> {
+    Connection::new_encrypted_with_settings(
         &["localhost:11729", "localhost:21729", "localhost:31729"],
         Credential::with_tls(
             "admin",
@@ -43,6 +52,7 @@ pub fn new_cluster_connection() -> typedb_client::Result<Connection> {
                     .expect("ROOT_CA environment variable needs to be set for cluster tests to run"),
             )),
         )?,
+        settings,
     )
 }
 
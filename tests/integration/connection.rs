@@ -0,0 +1,119 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use serial_test::serial;
+use typedb_client::{
+    Connection, ConnectionSettings, DatabaseManager, Session, SessionType::Data, TransactionType::Write,
+};
+
+use super::common;
+
+fn unbatched_settings() -> ConnectionSettings {
+    ConnectionSettings::new()
+        .transaction_dispatch_interval(Duration::ZERO)
+        .transaction_max_message_len(16)
+        .session_pulse_interval(Duration::from_secs(1))
+        .connect_timeout(Duration::from_secs(5))
+        .keepalive_interval(Duration::from_secs(10))
+}
+
+async fn insert_and_match(connection: Connection) -> typedb_client::Result {
+    common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+    let databases = DatabaseManager::new(connection);
+
+    let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Write).await?;
+    for _ in 0..10 {
+        let _ = transaction.query().insert("insert $x isa person;")?.collect::<Vec<_>>().await;
+    }
+    let results: Vec<_> = transaction.query().match_("match $x isa person;")?.collect().await;
+    transaction.commit().await?;
+    assert_eq!(results.len(), 10);
+    assert!(results.into_iter().all(|res| res.is_ok()));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial(core)]
+async fn core_unbatched_settings() -> typedb_client::Result {
+    insert_and_match(common::new_core_connection_with_settings(unbatched_settings())?).await
+}
+
+#[tokio::test]
+#[serial(cluster)]
+async fn cluster_unbatched_settings() -> typedb_client::Result {
+    insert_and_match(common::new_cluster_connection_with_settings(unbatched_settings())?).await
+}
+
+#[tokio::test]
+#[serial(cluster)]
+async fn cluster_refresh_servers() -> typedb_client::Result {
+    let settings = ConnectionSettings::new().server_refresh_interval(Duration::from_millis(100));
+    let connection = common::new_cluster_connection_with_settings(settings)?;
+    connection.refresh_servers()?;
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    insert_and_match(connection).await
+}
+
+#[tokio::test]
+#[serial(core)]
+async fn core_refresh_servers() -> typedb_client::Result {
+    let connection = common::new_core_connection()?;
+    connection.refresh_servers()?;
+    insert_and_match(connection).await
+}
+
+#[tokio::test]
+#[serial(core)]
+async fn core_servers_status() -> typedb_client::Result {
+    let connection = common::new_core_connection()?;
+    common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+
+    let statuses = connection.servers_status().await?;
+    assert_eq!(statuses.len(), 1);
+    assert!(statuses[0].is_reachable);
+    assert!(statuses[0].latency.is_some());
+    assert!(statuses[0].primary_databases.iter().any(|name| name == common::TEST_DATABASE));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial(cluster)]
+async fn cluster_servers_status() -> typedb_client::Result {
+    let connection = common::new_cluster_connection()?;
+    common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+
+    let statuses = connection.servers_status().await?;
+    assert_eq!(statuses.len(), 3);
+    assert!(statuses.iter().all(|status| status.is_reachable && status.latency.is_some()));
+    let primaries = statuses
+        .iter()
+        .filter(|status| status.primary_databases.iter().any(|name| name == common::TEST_DATABASE))
+        .count();
+    assert_eq!(primaries, 1);
+
+    Ok(())
+}
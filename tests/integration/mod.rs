@@ -20,5 +20,7 @@
  */
 
 mod common;
+mod connection;
 mod queries;
 mod runtimes;
+mod users;
@@ -26,12 +26,17 @@ use futures::StreamExt;
 use serial_test::serial;
 use tokio::sync::mpsc;
 use typedb_client::{
-    concept::{Attribute, Concept, DateTimeAttribute, LongAttribute, StringAttribute, Thing},
-    error::ConnectionError,
+    answer::{ConceptMap, FromConcept, FromConceptMap},
+    concept::{attribute::ValueType, Attribute, Concept, Thing, ThingType, Type},
+    error::{ConceptError, ConnectionError},
     Connection, DatabaseManager, Error, Options, Session,
     SessionType::{Data, Schema},
     TransactionType::{Read, Write},
 };
+use typeql_lang::{
+    parse_pattern, parse_variable,
+    pattern::{Pattern, Variable},
+};
 
 use super::common;
 
@@ -101,7 +106,45 @@ test_for_each_arg! {
         let answer_stream = transaction.query().match_("match $x sub nonexistent-type;")?;
         let results: Vec<_> = answer_stream.collect().await;
         assert_eq!(results.len(), 1);
-        assert!(results.into_iter().all(|res| res.unwrap_err().to_string().contains("[TYR03]")));
+        for result in results {
+            match result.unwrap_err() {
+                Error::Server(error) => {
+                    assert_eq!(error.code, "TYR03");
+                    assert!(!error.is_schema_violation() && !error.is_commit_conflict());
+                    assert!(error.to_string().contains("[TYR03]"));
+                }
+                error => panic!("expected a server error, got {error:?}"),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn close_callbacks(connection: Connection) -> typedb_client::Result {
+        common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let transaction = session.transaction(Read).await?;
+        let close_sender = sender.clone();
+        transaction.on_close(move |reason| close_sender.send(reason).unwrap());
+        transaction.close().await?;
+        assert_eq!(receiver.recv().await, Some(None));
+
+        let transaction = session.transaction(Read).await?;
+        let close_sender = sender.clone();
+        transaction.on_close(move |reason| close_sender.send(reason).unwrap());
+        let _: Vec<_> = transaction.query().match_("match $x sub nonexistent-type;")?.collect().await;
+        assert!(matches!(receiver.recv().await, Some(Some(Error::Server(_)))));
+        assert!(!transaction.is_open());
+        assert!(matches!(transaction.close().await, Err(Error::Server(_))));
+
+        let session_sender = sender.clone();
+        session.on_close(move |reason| session_sender.send(reason).unwrap());
+        session.force_close()?;
+        assert_eq!(receiver.recv().await, Some(None));
 
         Ok(())
     }
@@ -139,6 +182,61 @@ test_for_each_arg! {
         Ok(())
     }
 
+    async fn run_in_transaction(connection: Connection) -> typedb_client::Result {
+        common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let inserted = session
+            .run_in_transaction(Write, Options::new(), |transaction| {
+                Box::pin(async move {
+                    let answers: Vec<_> = transaction.query().insert("insert $x isa person;")?.collect().await;
+                    Ok(answers.len())
+                })
+            })
+            .await?;
+        assert_eq!(inserted, 1);
+
+        let transaction = session.transaction(Read).await?;
+        let person_count = transaction.query().match_aggregate("match $x isa person; count;").await?;
+        assert_eq!(person_count.into_i64(), 1);
+
+        let result = session
+            .run_in_transaction(Write, Options::new(), |transaction| {
+                Box::pin(async move {
+                    let answers: Vec<_> = transaction.query().match_("match $x isa nonexistent-type;")?.collect().await;
+                    answers.into_iter().collect::<typedb_client::Result<Vec<_>>>()
+                })
+            })
+            .await;
+        assert!(result.unwrap_err().to_string().contains("[TYR03]"));
+
+        Ok(())
+    }
+
+    async fn read_any_replica(connection: Connection) -> typedb_client::Result {
+        common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+        let databases = DatabaseManager::new(connection);
+
+        let options = Options::new().read_any_replica(true);
+        let session = Session::new_with_options(databases.get(common::TEST_DATABASE).await?, Data, options).await?;
+        for _ in 0..3 {
+            let transaction = session.transaction(Read).await?;
+            let results: Vec<_> = transaction.query().match_("match $x sub thing;")?.collect().await;
+            assert_eq!(results.len(), 5);
+        }
+
+        let transaction = session.transaction(Write).await?;
+        let _ = transaction.query().insert("insert $x isa person;")?.collect::<Vec<_>>().await;
+        transaction.commit().await?;
+
+        let transaction = session.transaction(Read).await?;
+        let person_count = transaction.query().match_aggregate("match $x isa person; count;").await?;
+        assert_eq!(person_count.into_i64(), 1);
+
+        Ok(())
+    }
+
     async fn query_options(connection: Connection) -> typedb_client::Result {
         let schema = r#"define
             person sub entity,
@@ -194,7 +292,7 @@ test_for_each_arg! {
         let mut ages = transaction.query().match_("match $age isa age;")?;
         while let Some(age) = ages.next().await {
             assert!(age.is_ok());
-            let age = unwrap_long(age?.map.remove("age").unwrap());
+            let age = i64::try_from(age?.get("age").unwrap())?;
             assert_eq!(age, 1);
         }
         drop(transaction);
@@ -221,6 +319,270 @@ test_for_each_arg! {
         Ok(())
     }
 
+    async fn group_queries(connection: Connection) -> typedb_client::Result {
+        let schema = r#"define
+            person sub entity,
+                owns name;
+            name sub attribute, value string;"#;
+        common::create_test_database_with_schema(connection.clone(), schema).await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let transaction = session.transaction(Write).await?;
+        let data = "insert $x isa person, has name 'Alice', has name 'Al'; $y isa person, has name 'Bob';";
+        let _ = transaction.query().insert(data);
+        transaction.commit().await?;
+
+        let transaction = session.transaction(Read).await?;
+        let groups: Vec<_> =
+            transaction.query().match_group("match $x isa person, has name $n; group $x;")?.collect().await;
+        assert_eq!(groups.len(), 2);
+        let mut group_sizes: Vec<_> =
+            groups.into_iter().map(|group| group.map(|group| group.concept_maps.len())).collect::<Result<_, _>>()?;
+        group_sizes.sort();
+        assert_eq!(group_sizes, vec![1, 2]);
+
+        let counts: Vec<_> = transaction
+            .query()
+            .match_group_aggregate("match $x isa person, has name $n; group $x; count;")?
+            .collect()
+            .await;
+        assert_eq!(counts.len(), 2);
+        let mut counts: Vec<_> =
+            counts.into_iter().map(|group| group.map(|group| group.numeric.into_i64())).collect::<Result<_, _>>()?;
+        counts.sort();
+        assert_eq!(counts, vec![1, 2]);
+
+        Ok(())
+    }
+
+    async fn explanations(connection: Connection) -> typedb_client::Result {
+        let schema = r#"define
+            person sub entity,
+                owns name,
+                owns age;
+            name sub attribute, value string;
+            age sub attribute, value long;
+            rule age-rule: when { $x isa person; } then { $x has age 25; };"#;
+        common::create_test_database_with_schema(connection.clone(), schema).await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let transaction = session.transaction(Write).await?;
+        let data = "insert $x isa person, has name 'Alice';";
+        let _ = transaction.query().insert(data);
+        transaction.commit().await?;
+
+        let with_explanations = Options::new().infer(true).explain(true);
+        let transaction = session.transaction_with_options(Read, with_explanations).await?;
+        let answers: Vec<_> = transaction.query().match_("match $x isa person, has age $a;")?.collect().await;
+        assert_eq!(answers.len(), 1);
+
+        let answer = answers.into_iter().next().unwrap()?;
+        let explainable = answer.explainables().ownership("x", "a").cloned().unwrap();
+        let explanations: Vec<_> = transaction.query().explain(&explainable)?.collect().await;
+        assert_eq!(explanations.len(), 1);
+        for explanation in explanations {
+            let explanation = explanation?;
+            assert_eq!(explanation.rule.label, "age-rule");
+            assert!(explanation.conclusion.get("x").is_some());
+        }
+
+        Ok(())
+    }
+
+    async fn thing_type_api(connection: Connection) -> typedb_client::Result {
+        let schema = r#"define
+            person sub entity,
+                owns name,
+                plays friendship:friend;
+            name sub attribute, value string;
+            friendship sub relation,
+                relates friend;"#;
+        common::create_test_database_with_schema(connection.clone(), schema).await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Schema).await?;
+        let transaction = session.transaction(Write).await?;
+        let mut answers: Vec<_> = transaction.query().match_("match $x type person;")?.collect().await;
+        assert_eq!(answers.len(), 1);
+        let mut person = match answers.pop().unwrap()?.map.remove("x").unwrap() {
+            Concept::Type(Type::Thing(ThingType::Entity(person))) => person,
+            _ => unreachable!(),
+        };
+
+        let supertype = person.get_supertype(&transaction).await?.unwrap();
+        assert_eq!(supertype.label(), "entity");
+        let supertypes: Vec<_> = person.get_supertypes(&transaction)?.collect().await;
+        assert_eq!(supertypes.len(), 3); // person, entity, thing
+
+        let owns: Vec<_> = person.get_owns(&transaction, None, false)?.collect().await;
+        assert_eq!(owns.len(), 1);
+        assert_eq!(owns.into_iter().next().unwrap()?.label(), "name");
+
+        let plays: Vec<_> = person.get_plays(&transaction)?.collect().await;
+        assert_eq!(plays.len(), 1);
+        assert_eq!(plays.into_iter().next().unwrap()?.label.to_string(), "friendship:friend");
+
+        person.set_label(&transaction, "human").await?;
+        assert_eq!(person.label, "human");
+        person.set_abstract(&transaction).await?;
+        let subtypes: Vec<_> = supertype.get_subtypes(&transaction)?.collect().await;
+        assert!(subtypes.into_iter().any(|subtype| subtype.map_or(false, |subtype| subtype.label() == "human")));
+
+        person.delete(&transaction).await?;
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn thing_api(connection: Connection) -> typedb_client::Result {
+        let schema = r#"define
+            person sub entity,
+                owns name,
+                plays friendship:friend;
+            name sub attribute, value string;
+            friendship sub relation,
+                relates friend;"#;
+        common::create_test_database_with_schema(connection.clone(), schema).await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let transaction = session.transaction(Write).await?;
+        let data = r#"insert
+            $x isa person, has name "Alice";
+            $y isa person, has name "Bob";
+            $f (friend: $x, friend: $y) isa friendship;"#;
+        let _ = transaction.query().insert(data);
+
+        let mut answers: Vec<_> = transaction
+            .query()
+            .match_(r#"match $x isa person, has name "Alice"; $f (friend: $x) isa friendship;"#)?
+            .collect()
+            .await;
+        assert_eq!(answers.len(), 1);
+        let mut answer = answers.pop().unwrap()?;
+        let alice = match answer.map.remove("x").unwrap() {
+            Concept::Thing(alice) => alice,
+            _ => unreachable!(),
+        };
+        let friendship = match answer.map.remove("f").unwrap() {
+            Concept::Thing(Thing::Relation(friendship)) => friendship,
+            _ => unreachable!(),
+        };
+
+        let has: Vec<_> = alice.get_has(&transaction, vec![], false)?.collect().await;
+        assert_eq!(has.len(), 1);
+        let name = has.into_iter().next().unwrap()?;
+        let owners: Vec<_> = name.get_owners(&transaction, None)?.collect().await;
+        assert_eq!(owners.len(), 1);
+        assert_eq!(owners.into_iter().next().unwrap()?.iid(), alice.iid());
+
+        let relations: Vec<_> = alice.get_relations(&transaction, vec![])?.collect().await;
+        assert_eq!(relations.len(), 1);
+        let playing: Vec<_> = alice.get_playing(&transaction)?.collect().await;
+        assert_eq!(playing.len(), 1);
+        let players: Vec<_> = friendship.get_players_by_role_type(&transaction)?.collect().await;
+        assert_eq!(players.len(), 2);
+
+        let (role_type, _) = players.into_iter().next().unwrap()?;
+        friendship.remove_player(&transaction, role_type.clone(), alice.clone()).await?;
+        let players: Vec<_> = friendship.get_players_by_role_type(&transaction)?.collect().await;
+        assert_eq!(players.len(), 1);
+        friendship.add_player(&transaction, role_type, alice.clone()).await?;
+
+        alice.unset_has(&transaction, name.clone()).await?;
+        let has: Vec<_> = alice.get_has(&transaction, vec![], false)?.collect().await;
+        assert!(has.is_empty());
+        alice.set_has(&transaction, name).await?;
+
+        friendship.delete(&transaction).await?;
+        let relations: Vec<_> = alice.get_relations(&transaction, vec![])?.collect().await;
+        assert!(relations.is_empty());
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    async fn concept_manager(connection: Connection) -> typedb_client::Result {
+        common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Schema).await?;
+        let transaction = session.transaction(Write).await?;
+        let concepts = transaction.concepts();
+        assert!(concepts.get_entity_type("person").await?.is_some());
+        assert!(concepts.get_relation_type("person").await?.is_none());
+        assert!(concepts.get_entity_type("company").await?.is_none());
+
+        let company = concepts.put_entity_type("company").await?;
+        assert_eq!(company.label, "company");
+        let employment = concepts.put_relation_type("employment").await?;
+        assert_eq!(employment.label, "employment");
+        let name = concepts.put_attribute_type("name", ValueType::String).await?;
+        assert!(matches!(name.value_type(), ValueType::String));
+        assert!(concepts.get_attribute_type("name").await?.is_some());
+
+        let exceptions: Vec<_> = concepts.get_schema_exceptions()?.collect().await;
+        assert!(exceptions.is_empty());
+        transaction.commit().await?;
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let transaction = session.transaction(Write).await?;
+        let mut answers: Vec<_> = transaction.query().insert("insert $x isa person;")?.collect().await;
+        let person = match answers.pop().unwrap()?.map.remove("x").unwrap() {
+            Concept::Thing(person) => person,
+            _ => unreachable!(),
+        };
+        let thing = transaction.concepts().get_thing(person.iid()).await?;
+        assert_eq!(thing.unwrap().iid(), person.iid());
+
+        Ok(())
+    }
+
+    async fn logic_manager(connection: Connection) -> typedb_client::Result {
+        let schema = r#"define
+            person sub entity,
+                owns name,
+                owns age;
+            name sub attribute, value string;
+            age sub attribute, value long;
+            rule age-rule: when { $x isa person; } then { $x has age 25; };"#;
+        common::create_test_database_with_schema(connection.clone(), schema).await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Schema).await?;
+        let transaction = session.transaction(Write).await?;
+        let logic = transaction.logic();
+        let mut rule = logic.get_rule("age-rule").await?.unwrap();
+        assert_eq!(rule.label, "age-rule");
+        assert!(logic.get_rule("name-rule").await?.is_none());
+
+        let when = match parse_pattern("{ $x isa person; }").unwrap() {
+            Pattern::Conjunction(conjunction) => conjunction,
+            _ => unreachable!(),
+        };
+        let then = match parse_variable(r#"$x has name "Alice""#).unwrap() {
+            Variable::Thing(thing) => thing,
+            _ => unreachable!(),
+        };
+        let name_rule = logic.put_rule("name-rule", when, then).await?;
+        assert_eq!(name_rule.label, "name-rule");
+        let rules: Vec<_> = logic.get_rules()?.collect().await;
+        assert_eq!(rules.len(), 2);
+
+        rule.set_label(&transaction, "adult-rule").await?;
+        assert_eq!(rule.label, "adult-rule");
+        assert!(logic.get_rule("adult-rule").await?.is_some());
+
+        assert!(!name_rule.is_deleted(&transaction).await?);
+        name_rule.delete(&transaction).await?;
+        assert!(name_rule.is_deleted(&transaction).await?);
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
     async fn many_concept_types(connection: Connection) -> typedb_client::Result {
         let schema = r#"define
             person sub entity,
@@ -252,9 +614,13 @@ test_for_each_arg! {
 
         while let Some(result) = answer_stream.next().await {
             assert!(result.is_ok());
-            let mut result = result?.map;
-            let name = unwrap_string(result.remove("name").unwrap());
-            let date_of_birth = unwrap_date_time(result.remove("date-of-birth").unwrap()).date();
+            let result = result?;
+            let name = String::try_from(result.get("name").unwrap())?;
+            let date_of_birth = NaiveDateTime::try_from(result.get("date-of-birth").unwrap())?.date();
+            assert_eq!(
+                i64::try_from(result.get("name").unwrap()),
+                Err(Error::Concept(ConceptError::UnexpectedValueType(ValueType::Long, ValueType::String)))
+            );
             match name.as_str() {
                 "Alice" => assert_eq!(date_of_birth, NaiveDate::from_ymd_opt(1994, 10, 3).unwrap()),
                 "Bob" => assert_eq!(date_of_birth, NaiveDate::from_ymd_opt(1993, 4, 17).unwrap()),
@@ -265,6 +631,59 @@ test_for_each_arg! {
         Ok(())
     }
 
+    async fn typed_answers(connection: Connection) -> typedb_client::Result {
+        let schema = r#"define
+            person sub entity,
+                owns name,
+                owns date-of-birth,
+                owns nickname;
+            name sub attribute, value string;
+            date-of-birth sub attribute, value datetime;
+            nickname sub attribute, value string;"#;
+        common::create_test_database_with_schema(connection.clone(), schema).await?;
+        let databases = DatabaseManager::new(connection);
+
+        let session = Session::new(databases.get(common::TEST_DATABASE).await?, Data).await?;
+        let transaction = session.transaction(Write).await?;
+        let data = r#"insert $x isa person, has name "Alice", has date-of-birth 1994-10-03;"#;
+        let _ = transaction.query().insert(data);
+        transaction.commit().await?;
+
+        struct Person {
+            x: Vec<u8>,
+            name: String,
+            date_of_birth: NaiveDateTime,
+            nickname: Option<String>,
+        }
+
+        impl FromConceptMap for Person {
+            fn from_concept_map(concept_map: ConceptMap) -> typedb_client::Result<Self> {
+                Ok(Self {
+                    x: Thing::from_variable(&concept_map, "x")?.iid().to_vec(),
+                    name: String::from_variable(&concept_map, "name")?,
+                    date_of_birth: NaiveDateTime::from_variable(&concept_map, "date-of-birth")?,
+                    nickname: Option::<String>::from_variable(&concept_map, "nickname")?,
+                })
+            }
+        }
+
+        let transaction = session.transaction(Read).await?;
+        let people: Vec<Person> = transaction
+            .query()
+            .match_as("match $x isa person, has name $name, has date-of-birth $date-of-birth;")?
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<typedb_client::Result<_>>()?;
+        assert_eq!(people.len(), 1);
+        assert!(!people[0].x.is_empty());
+        assert_eq!(people[0].name, "Alice");
+        assert_eq!(people[0].date_of_birth.date(), NaiveDate::from_ymd_opt(1994, 10, 3).unwrap());
+        assert!(people[0].nickname.is_none());
+
+        Ok(())
+    }
+
     async fn force_close_connection(connection: Connection) -> typedb_client::Result {
         common::create_test_database_with_schema(connection.clone(), "define person sub entity;").await?;
         let databases = DatabaseManager::new(connection.clone());
@@ -367,26 +786,3 @@ test_for_each_arg! {
         Ok(())
     }
 }
-
-// Concept helpers
-// FIXME: should be removed after concept API is implemented
-fn unwrap_date_time(concept: Concept) -> NaiveDateTime {
-    match concept {
-        Concept::Thing(Thing::Attribute(Attribute::DateTime(DateTimeAttribute { value, .. }))) => value,
-        _ => unreachable!(),
-    }
-}
-
-fn unwrap_string(concept: Concept) -> String {
-    match concept {
-        Concept::Thing(Thing::Attribute(Attribute::String(StringAttribute { value, .. }))) => value,
-        _ => unreachable!(),
-    }
-}
-
-fn unwrap_long(concept: Concept) -> i64 {
-    match concept {
-        Concept::Thing(Thing::Attribute(Attribute::Long(LongAttribute { value, .. }))) => value,
-        _ => unreachable!(),
-    }
-}
@@ -21,7 +21,11 @@
 
 use futures::StreamExt;
 use serial_test::serial;
-use typedb_client::{DatabaseManager, Session, SessionType::Data, TransactionType::Write};
+use typedb_client::{
+    blocking, DatabaseManager, Session,
+    SessionType::{Data, Schema},
+    TransactionType::Write,
+};
 
 use super::common;
 
@@ -87,3 +91,27 @@ fn basic_futures() {
     })
     .unwrap();
 }
+
+#[test]
+#[serial]
+fn basic_blocking() -> typedb_client::Result {
+    let databases = blocking::DatabaseManager::new(common::new_cluster_connection()?);
+    if databases.contains(common::TEST_DATABASE)? {
+        databases.get(common::TEST_DATABASE)?.delete()?;
+    }
+    databases.create(common::TEST_DATABASE)?;
+    assert!(databases.contains(common::TEST_DATABASE)?);
+
+    let session = blocking::Session::new(databases.get(common::TEST_DATABASE)?, Schema)?;
+    let transaction = session.transaction(Write)?;
+    transaction.query().define("define person sub entity;")?;
+    transaction.commit()?;
+
+    let session = blocking::Session::new(databases.get(common::TEST_DATABASE)?, Data)?;
+    let transaction = session.transaction(Write)?;
+    let results: Vec<_> = transaction.query().match_("match $x sub thing;")?.collect();
+    transaction.commit()?;
+    assert_eq!(results.len(), 5);
+    assert!(results.into_iter().all(|res| res.is_ok()));
+    Ok(())
+}
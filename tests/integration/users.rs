@@ -0,0 +1,60 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use serial_test::serial;
+use typedb_client::{error::ConnectionError, Error, UserManager};
+
+use super::common;
+
+const TEST_USER: &str = "test-user";
+
+#[tokio::test]
+#[serial]
+async fn user_management() -> typedb_client::Result {
+    let users = UserManager::new(common::new_cluster_connection()?);
+    if users.contains(TEST_USER).await? {
+        users.delete(TEST_USER).await?;
+    }
+
+    users.create(TEST_USER, "password").await?;
+    assert!(users.contains(TEST_USER).await?);
+    assert!(users.all().await?.iter().any(|user| user.username() == TEST_USER));
+
+    let user = users.get(TEST_USER).await?.unwrap();
+    assert_eq!(user.username(), TEST_USER);
+    user.password_update("password", "new-password").await?;
+    users.password_set(TEST_USER, "password").await?;
+
+    users.delete(TEST_USER).await?;
+    assert!(!users.contains(TEST_USER).await?);
+    assert!(users.get(TEST_USER).await?.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[serial]
+async fn user_management_requires_cluster() -> typedb_client::Result {
+    let users = UserManager::new(common::new_core_connection()?);
+    assert_eq!(users.all().await.unwrap_err(), Error::Connection(ConnectionError::UserManagementClusterOnly()));
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+/*
+ * Copyright (C) 2022 Vaticle
+ *
+ * Licensed to the Apache Software Foundation (ASF) under one
+ * or more contributor license agreements.  See the NOTICE file
+ * distributed with this work for additional information
+ * regarding copyright ownership.  The ASF licenses this file
+ * to you under the Apache License, Version 2.0 (the
+ * "License"); you may not use this file except in compliance
+ * with the License.  You may obtain a copy of the License at
+ *
+ *   http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing,
+ * software distributed under the License is distributed on an
+ * "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+ * KIND, either express or implied.  See the License for the
+ * specific language governing permissions and limitations
+ * under the License.
+ */
+
+use std::time::{Duration, Instant};
+
+use futures::TryStreamExt;
+use metrics_util::debugging::{DebugValue, DebuggingRecorder, Snapshotter};
+use tokio::time::sleep;
+use typedb_client::{
+    testing::{MockResponse, MockServer},
+    Connection, DatabaseManager, Session,
+    SessionType::Data,
+    TransactionType::Read,
+};
+
+const METRICS_DATABASE: &str = "metrics";
+
+type Snapshot = Vec<(String, DebugValue)>;
+
+/// Taking a snapshot drains the recorded histogram samples, so all assertions on one state share a snapshot.
+fn snapshot(snapshotter: &Snapshotter) -> Snapshot {
+    snapshotter.snapshot().into_vec().into_iter().map(|(key, .., value)| (key.key().name().to_owned(), value)).collect()
+}
+
+fn metric_values<'a>(snapshot: &'a Snapshot, name: &str) -> Vec<&'a DebugValue> {
+    snapshot.iter().filter(|(key, _)| key == name).map(|(_, value)| value).collect()
+}
+
+fn counter(snapshot: &Snapshot, name: &str) -> u64 {
+    metric_values(snapshot, name)
+        .into_iter()
+        .map(|value| match value {
+            DebugValue::Counter(value) => *value,
+            other => panic!("{name} is not a counter: {other:?}"),
+        })
+        .sum()
+}
+
+fn gauge(snapshot: &Snapshot, name: &str) -> f64 {
+    metric_values(snapshot, name)
+        .into_iter()
+        .map(|value| match value {
+            DebugValue::Gauge(value) => value.into_inner(),
+            other => panic!("{name} is not a gauge: {other:?}"),
+        })
+        .sum()
+}
+
+fn histogram_samples(snapshot: &Snapshot, name: &str) -> usize {
+    metric_values(snapshot, name)
+        .into_iter()
+        .map(|value| match value {
+            DebugValue::Histogram(values) => values.len(),
+            other => panic!("{name} is not a histogram: {other:?}"),
+        })
+        .sum()
+}
+
+#[tokio::test]
+async fn metrics_are_reported() -> typedb_client::Result {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    recorder.install().expect("another metrics recorder is already installed");
+
+    let server = MockServer::start()?;
+    server.on_query("match $x isa person;", MockResponse::Pages(3));
+    let databases = DatabaseManager::new(Connection::new_plaintext(server.address())?);
+    databases.create(METRICS_DATABASE).await?;
+
+    let session = Session::new(databases.get(METRICS_DATABASE).await?, Data).await?;
+    let transaction = session.transaction(Read).await?;
+    let answers: Vec<_> = transaction.query().match_("match $x isa person;")?.try_collect().await?;
+    assert_eq!(answers.len(), 3);
+
+    let metrics = snapshot(&snapshotter);
+    assert_eq!(gauge(&metrics, "typedb_client_open_sessions"), 1.0);
+    assert_eq!(gauge(&metrics, "typedb_client_open_transactions"), 1.0);
+    assert_eq!(counter(&metrics, "typedb_client_answers_total"), 3);
+    assert!(histogram_samples(&metrics, "typedb_client_request_duration_seconds") > 0);
+    assert!(histogram_samples(&metrics, "typedb_client_transaction_batch_requests") > 0);
+    assert!(histogram_samples(&metrics, "typedb_client_transaction_batch_bytes") > 0);
+
+    transaction.close().await?;
+    session.force_close()?;
+
+    // Transactions are closed by the client's background runtime, so the gauge may lag behind.
+    let start = Instant::now();
+    while gauge(&snapshot(&snapshotter), "typedb_client_open_transactions") != 0.0 {
+        assert!(start.elapsed() < Duration::from_secs(5), "the open transactions gauge did not return to zero");
+        sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(gauge(&snapshot(&snapshotter), "typedb_client_open_sessions"), 0.0);
+
+    Ok(())
+}